@@ -0,0 +1,93 @@
+use std::str::FromStr;
+use thiserror::Error;
+use crate::{InputError, InputSource};
+
+/// Name of the file listing a day's known answers, kept next to its inputs
+pub const ANSWERS_FILE: &str = "answers.txt";
+
+#[derive(Debug, Error)]
+pub enum AnswersError {
+    #[error("Line {line}: expected `<file> <part 1> <part 2>`, found [{text}]")]
+    Malformed { line: usize, text: String },
+    #[error(transparent)]
+    Input(#[from] InputError),
+}
+
+/// Known answers for one of a day's input files; `None` for a part that isn't checked against that file
+#[derive(Debug, Clone, PartialEq)]
+pub struct Expected {
+    pub file: String,
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+impl Expected {
+    /// Whether the answers are for the real puzzle input rather than a sample
+    pub fn is_input(&self) -> bool {
+        self.file == "input.txt"
+    }
+}
+
+/// A day's answers file, one `<file> <part 1> <part 2>` entry per line, e.g.
+/// ```text
+/// # file       part 1     part 2
+/// sample.txt   35         46
+/// input.txt    388071289  84206669
+/// ```
+/// `-` marks a part that isn't checked against the file, while blank lines and `#` comments are ignored
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Answers(pub Vec<Expected>);
+
+impl Answers {
+    /// Loads the answers file of `day`, looked up the same way as the day's input
+    pub fn load(day: u8) -> Result<Answers, AnswersError> {
+        InputSource::day_file(day, ANSWERS_FILE).read()?.parse()
+    }
+    pub fn iter(&self) -> impl Iterator<Item=&Expected> {
+        self.0.iter()
+    }
+}
+
+impl FromStr for Answers {
+    type Err = AnswersError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let answer = |a: &str| if a == "-" { None } else { Some(a.to_string()) };
+
+        s.lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty() && !line.trim_start().starts_with('#'))
+            .map(|(i, line)| {
+                match line.split_whitespace().collect::<Vec<_>>()[..] {
+                    [file, part1, part2] => Ok(Expected { file: file.into(), part1: answer(part1), part2: answer(part2) }),
+                    _ => Err(AnswersError::Malformed { line: i + 1, text: line.into() }),
+                }
+            })
+            .collect::<Result<_,_>>()
+            .map(Answers)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_answers() {
+        let answers = "# file part1 part2\n\nsample.txt 35 46\nsample2.txt - 71\ninput.txt 388071289 84206669\n"
+            .parse::<Answers>()
+            .expect("Ops!");
+        assert_eq!(
+            answers.0,
+            [
+                Expected { file: "sample.txt".into(), part1: Some("35".into()), part2: Some("46".into()) },
+                Expected { file: "sample2.txt".into(), part1: None, part2: Some("71".into()) },
+                Expected { file: "input.txt".into(), part1: Some("388071289".into()), part2: Some("84206669".into()) },
+            ]
+        );
+        assert_eq!(answers.iter().filter(|e| e.is_input()).count(), 1);
+
+        let err = "sample.txt 35\n".parse::<Answers>().expect_err("missing part 2");
+        assert_eq!(err.to_string(), "Line 1: expected `<file> <part 1> <part 2>`, found [sample.txt 35]");
+    }
+}
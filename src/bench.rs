@@ -0,0 +1,271 @@
+use std::{fmt::Display, hint::black_box, io::Write, str::FromStr, time::{Duration, Instant}};
+use crate::{catch_panic, InputSource, Puzzle};
+
+/// Which of a day's bundled files the puzzle is measured against
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Dataset { Sample, Input }
+
+impl Dataset {
+    pub const ALL: [Dataset; 2] = [Dataset::Sample, Dataset::Input];
+
+    pub fn file_name(&self) -> &'static str {
+        match self {
+            Dataset::Sample => "sample.txt",
+            Dataset::Input => "input.txt",
+        }
+    }
+    pub fn source(&self, day: u8) -> InputSource {
+        InputSource::day_file(day, self.file_name())
+    }
+}
+
+impl Display for Dataset {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self { Dataset::Sample => "sample", Dataset::Input => "input" })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stage { Parse, Part1, Part2 }
+
+impl Display for Stage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self { Stage::Parse => "parse", Stage::Part1 => "part1", Stage::Part2 => "part2" })
+    }
+}
+
+/// Timings of a single stage of a day, over a number of runs
+#[derive(Debug, Clone, PartialEq)]
+pub struct Measurement {
+    pub day: u8,
+    pub dataset: Dataset,
+    pub stage: Stage,
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    /// The answer a part produced; `None` for parsing
+    pub answer: Option<String>,
+    /// Why the stage failed, in which case no run is timed
+    pub error: Option<String>,
+}
+
+impl Measurement {
+    fn new(day: u8, dataset: Dataset, stage: Stage, answer: Option<String>, mut samples: Vec<Duration>) -> Measurement {
+        samples.sort();
+        Measurement {
+            day, dataset, stage, answer,
+            error: None,
+            runs: samples.len(),
+            min: samples[0],
+            median: samples[samples.len() / 2],
+            mean: samples.iter().sum::<Duration>() / samples.len() as u32,
+        }
+    }
+    fn failed(day: u8, dataset: Dataset, stage: Stage, error: String) -> Measurement {
+        Measurement {
+            day, dataset, stage,
+            runs: 0,
+            min: Duration::ZERO,
+            median: Duration::ZERO,
+            mean: Duration::ZERO,
+            answer: None,
+            error: Some(error),
+        }
+    }
+}
+
+/// Runs `f` `runs` times, returning the last output along with the time each run took
+fn time<T>(runs: usize, mut f: impl FnMut() -> T) -> (T, Vec<Duration>) {
+    let mut out = None;
+    let samples = (0..runs.max(1))
+        .map(|_| {
+            let t = Instant::now();
+            let o = black_box(f());
+            let elapsed = t.elapsed();
+            // drop the previous output outside the timed section
+            out = Some(o);
+            elapsed
+        })
+        .collect();
+    (out.expect("at least one run"), samples)
+}
+
+/// Same as [`time`], catching a panic of `f` as an error rather than unwinding out of the benchmark
+fn try_time<T>(runs: usize, f: impl FnMut() -> T) -> Result<(T, Vec<Duration>), String> {
+    catch_panic(|| time(runs, f))
+}
+
+/// Times parsing, part 1 and part 2 of `puzzle` separately, each over `runs` runs
+///
+/// A stage that panics is recorded as failed and the remaining stages still run, bar both parts when parsing fails
+pub fn measure(puzzle: &dyn Puzzle, dataset: Dataset, input: &str, runs: usize) -> Vec<Measurement> {
+    let day = puzzle.day();
+    let (parsed, parse) = match try_time(runs, || puzzle.parse(input)) {
+        Ok(parsed) => parsed,
+        Err(e) => return vec![Measurement::failed(day, dataset, Stage::Parse, e)],
+    };
+    let mut report = vec![Measurement::new(day, dataset, Stage::Parse, None, parse)];
+    for stage in [Stage::Part1, Stage::Part2] {
+        let part = || if stage == Stage::Part1 { parsed.part1() } else { parsed.part2() };
+        report.push(match try_time(runs, part) {
+            Ok((answer, t)) => Measurement::new(day, dataset, stage, Some(answer), t),
+            Err(e) => Measurement::failed(day, dataset, stage, e),
+        });
+    }
+    report
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format { Json, Csv }
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!("invalid format {s:?}; expected json or csv")),
+        }
+    }
+}
+
+/// Writes the measurements with durations in nanoseconds, either as a JSON array or CSV with a header row
+pub fn write_report(w: &mut impl Write, format: Format, report: &[Measurement]) -> std::io::Result<()> {
+    match format {
+        Format::Csv => {
+            // quoted when holding a comma, quote or line break
+            let field = |f: Option<&str>| match f.unwrap_or("") {
+                f if f.contains([',', '"', '\n']) => format!("\"{}\"", f.replace('"', "\"\"")),
+                f => f.to_string(),
+            };
+            writeln!(w, "day,dataset,stage,runs,min_ns,median_ns,mean_ns,answer,error")?;
+            for m in report {
+                writeln!(w, "{},{},{},{},{},{},{},{},{}",
+                    m.day, m.dataset, m.stage, m.runs,
+                    m.min.as_nanos(), m.median.as_nanos(), m.mean.as_nanos(),
+                    field(m.answer.as_deref()), field(m.error.as_deref())
+                )?;
+            }
+        }
+        Format::Json => {
+            writeln!(w, "[")?;
+            // control characters such as a panic message's line breaks are escaped by code point
+            let string = |s: Option<&str>| s
+                .map(|s| s.chars().fold(String::from("\""), |mut out, c| {
+                    match c {
+                        '\\' | '"' => { out.push('\\'); out.push(c) },
+                        c if c < ' ' => out.push_str(&format!("\\u{:04x}", c as u32)),
+                        c => out.push(c),
+                    }
+                    out
+                }) + "\"")
+                .unwrap_or("null".into());
+            for (i, m) in report.iter().enumerate() {
+                writeln!(w,
+                    "  {{\"day\":{},\"dataset\":\"{}\",\"stage\":\"{}\",\"runs\":{},\"min_ns\":{},\"median_ns\":{},\"mean_ns\":{},\"answer\":{},\"error\":{}}}{}",
+                    m.day, m.dataset, m.stage, m.runs,
+                    m.min.as_nanos(), m.median.as_nanos(), m.mean.as_nanos(),
+                    string(m.answer.as_deref()), string(m.error.as_deref()), if i + 1 < report.len() { "," } else { "" }
+                )?;
+            }
+            writeln!(w, "]")?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn sample_report() -> Vec<Measurement> {
+        let ms = Duration::from_millis;
+        vec![
+            Measurement::new(5, Dataset::Sample, Stage::Parse, None, vec![ms(3), ms(1), ms(2)]),
+            Measurement::new(5, Dataset::Sample, Stage::Part1, Some("35".into()), vec![ms(4)]),
+            Measurement::failed(5, Dataset::Sample, Stage::Part2, "panicked: \"a\", b".into()),
+        ]
+    }
+
+    #[test]
+    fn test_measure_day() {
+        let input = std::fs::read_to_string("src/bin/day5/sample.txt").expect("Ops!");
+        let report = measure(crate::solution(5).unwrap(), Dataset::Sample, &input, 3);
+
+        assert_eq!(report.iter().map(|m| m.stage).collect::<Vec<_>>(), [Stage::Parse, Stage::Part1, Stage::Part2]);
+        assert_eq!(report.iter().map(|m| m.answer.as_deref()).collect::<Vec<_>>(), [None, Some("35"), Some("46")]);
+        assert!(report.iter().all(|m| m.runs == 3 && m.min <= m.median && m.error.is_none()));
+    }
+
+    // a puzzle whose part 1 panics, and whose parsing panics on an empty input
+    struct Faulty;
+    struct FaultySolve;
+
+    impl Puzzle for Faulty {
+        fn day(&self) -> u8 { 1 }
+        fn parse(&self, input: &str) -> Box<dyn crate::Solve> {
+            assert!(!input.is_empty(), "empty input");
+            Box::new(FaultySolve)
+        }
+    }
+    impl crate::Solve for FaultySolve {
+        fn part1(&self) -> String { panic!("part 1 {}", "failed") }
+        fn part2(&self) -> String { "42".into() }
+    }
+
+    #[test]
+    fn test_measure_panics() {
+        let report = measure(&Faulty, Dataset::Sample, "input", 2);
+        assert_eq!(
+            report.iter().map(|m| (m.stage, m.runs, m.answer.as_deref(), m.error.as_deref())).collect::<Vec<_>>(),
+            [
+                (Stage::Parse, 2, None, None),
+                (Stage::Part1, 0, None, Some("panicked: part 1 failed")),
+                (Stage::Part2, 2, Some("42"), None),
+            ]
+        );
+
+        let report = measure(&Faulty, Dataset::Sample, "", 2);
+        assert_eq!(report.len(), 1);
+        assert_eq!(report[0].error.as_deref(), Some("panicked: empty input"));
+    }
+
+    #[test]
+    fn test_write_csv() {
+        let mut out = vec![];
+        write_report(&mut out, Format::Csv, &sample_report()).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "day,dataset,stage,runs,min_ns,median_ns,mean_ns,answer,error\n\
+            5,sample,parse,3,1000000,2000000,2000000,,\n\
+            5,sample,part1,1,4000000,4000000,4000000,35,\n\
+            5,sample,part2,0,0,0,0,,\"panicked: \"\"a\"\", b\"\n"
+        );
+    }
+
+    #[test]
+    fn test_write_json() {
+        let mut out = vec![];
+        write_report(&mut out, Format::Json, &sample_report()).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "[\n  \
+            {\"day\":5,\"dataset\":\"sample\",\"stage\":\"parse\",\"runs\":3,\"min_ns\":1000000,\"median_ns\":2000000,\"mean_ns\":2000000,\"answer\":null,\"error\":null},\n  \
+            {\"day\":5,\"dataset\":\"sample\",\"stage\":\"part1\",\"runs\":1,\"min_ns\":4000000,\"median_ns\":4000000,\"mean_ns\":4000000,\"answer\":\"35\",\"error\":null},\n  \
+            {\"day\":5,\"dataset\":\"sample\",\"stage\":\"part2\",\"runs\":0,\"min_ns\":0,\"median_ns\":0,\"mean_ns\":0,\"answer\":null,\"error\":\"panicked: \\\"a\\\", b\"}\n\
+            ]\n"
+        );
+    }
+
+    #[test]
+    fn test_write_json_control_characters() {
+        let mut out = vec![];
+        let report = [Measurement::failed(5, Dataset::Sample, Stage::Part2, "panicked:\tline\r\nnext \\ \u{1b}".into())];
+        write_report(&mut out, Format::Json, &report).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.contains(r#""error":"panicked:\u0009line\u000d\u000anext \\ \u001b""#), "{out}");
+        assert!(!out.chars().any(|c| c < ' ' && c != '\n'));
+    }
+}
@@ -0,0 +1,104 @@
+use std::{ops::RangeInclusive, time::{Duration, Instant}};
+use advent2023::{all_days, catch_panic, parse_days, read_input, SOLUTIONS};
+
+static USAGE: &str = "\
+Usage: aoc [DAYS] [--part <1|2>] [--input <PATH|->]
+
+  DAYS          `all` (default), a single day `5`, a range `3..7` or a list `1,5,9`
+  -p, --part    run only part 1 or part 2 (default: both)
+  -i, --input   read the input of a single day from PATH, or from stdin with `-`
+  -h, --help    print this message
+
+Without --input, each day reads `$AOC_INPUTS/day<N>/input.txt` when AOC_INPUTS
+is set, otherwise the bundled `src/bin/day<N>/input.txt`. A day or part that
+panics, as on input it can't solve, is reported and the remaining ones still run.";
+
+struct Args {
+    days: Vec<u8>,
+    parts: RangeInclusive<u8>,
+    input: Option<String>,
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Args, String> {
+    let mut days = None;
+    let mut parts = 1..=2;
+    let mut input = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => return Err(USAGE.into()),
+            "-p" | "--part" => {
+                parts = match args.next().as_deref() {
+                    Some("1") => 1..=1,
+                    Some("2") => 2..=2,
+                    other => return Err(format!("invalid part {other:?}; expected 1 or 2")),
+                }
+            }
+            "-i" | "--input" => {
+                input = Some(args.next().ok_or("missing path after --input")?)
+            }
+            _ if days.is_none() => days = Some(parse_days(&arg)?),
+            _ => return Err(format!("unexpected argument {arg:?}\n\n{USAGE}")),
+        }
+    }
+
+    let days = days.unwrap_or_else(all_days);
+    if input.is_some() && days.len() != 1 {
+        return Err("--input can only be used when running a single day".into())
+    }
+    Ok(Args { days, parts, input })
+}
+
+fn main() {
+    let args = match parse_args(std::env::args().skip(1)) {
+        Ok(args) => args,
+        Err(msg) => { eprintln!("{msg}"); std::process::exit(2) }
+    };
+
+    // panicking solvers are reported along with the results, rather than on stderr as well
+    std::panic::set_hook(Box::new(|_| {}));
+
+    println!("{:>4} | {:>5} | {:>20} | {:>12}", "Day", "Part", "Answer", "Time");
+    println!("{:-<5}+{:-<7}+{:-<22}+{:-<13}", "", "", "", "");
+
+    let mut total = Duration::ZERO;
+    for puzzle in SOLUTIONS.iter().filter(|p| args.days.contains(&p.day())) {
+        let day = puzzle.day();
+        let input = match read_input(day, args.input.as_deref()) {
+            Ok(input) => input,
+            Err(e) => {
+                println!("{day:>4} | {:>5} | {e}", "-");
+                continue
+            }
+        };
+
+        let t = Instant::now();
+        let parsed = match catch_panic(|| puzzle.parse(&input)) {
+            Ok(parsed) => parsed,
+            Err(e) => {
+                println!("{day:>4} | {:>5} | {e}", "parse");
+                continue
+            }
+        };
+        let elapsed = t.elapsed();
+        total += elapsed;
+        println!("{day:>4} | {:>5} | {:>20} | {:>12}", "parse", "", format!("{elapsed:.2?}"));
+
+        for part in args.parts.clone() {
+            let t = Instant::now();
+            let answer = match catch_panic(|| if part == 1 { parsed.part1() } else { parsed.part2() }) {
+                Ok(answer) => answer,
+                Err(e) => {
+                    println!("{day:>4} | {part:>5} | {e}");
+                    continue
+                }
+            };
+            let elapsed = t.elapsed();
+            total += elapsed;
+            println!("{day:>4} | {part:>5} | {answer:>20} | {:>12}", format!("{elapsed:.2?}"));
+        }
+    }
+
+    println!("{:-<5}+{:-<7}+{:-<22}+{:-<13}", "", "", "", "");
+    println!("{:>4}   {:>5}   {:>20} | {:>12}", "", "", "Total", format!("{total:.2?}"));
+}
@@ -0,0 +1,105 @@
+use std::io::Write;
+use advent2023::{all_days, parse_days, SOLUTIONS};
+use advent2023::bench::{measure, write_report, Dataset, Format};
+
+static USAGE: &str = "\
+Usage: bench [DAYS] [--runs <N>] [--dataset <sample|input|all>] [--format <json|csv>] [--output <PATH>]
+
+  DAYS           `all` (default), a single day `5`, a range `3..7` or a list `1,5,9`
+  -r, --runs     times each of parse, part 1 and part 2 N times (default: 5)
+  -d, --dataset  measure against `sample.txt`, `input.txt` or both (default: all)
+  -f, --format   write the results as json (default) or csv
+  -o, --output   write the results to PATH instead of stdout
+  -h, --help     print this message
+
+Days without the requested file are skipped. A stage that panics is reported
+with its error and the remaining days are still measured. Files are looked up under
+`$AOC_INPUTS/day<N>/` when AOC_INPUTS is set, otherwise `src/bin/day<N>/`.
+Build with --release for meaningful numbers.";
+
+struct Args {
+    days: Vec<u8>,
+    runs: usize,
+    datasets: Vec<Dataset>,
+    format: Format,
+    output: Option<String>,
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Args, String> {
+    let mut days = None;
+    let mut runs = 5;
+    let mut datasets = Dataset::ALL.to_vec();
+    let mut format = Format::Json;
+    let mut output = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => return Err(USAGE.into()),
+            "-r" | "--runs" => {
+                runs = match args.next().map(|n| n.parse::<usize>()) {
+                    Some(Ok(n)) if n > 0 => n,
+                    _ => return Err("invalid --runs; expected a positive number".into()),
+                }
+            }
+            "-d" | "--dataset" => {
+                datasets = match args.next().as_deref() {
+                    Some("sample") => vec![Dataset::Sample],
+                    Some("input") => vec![Dataset::Input],
+                    Some("all") => Dataset::ALL.to_vec(),
+                    other => return Err(format!("invalid dataset {other:?}; expected sample, input or all")),
+                }
+            }
+            "-f" | "--format" => {
+                format = args.next().ok_or("missing format after --format")?.parse()?
+            }
+            "-o" | "--output" => {
+                output = Some(args.next().ok_or("missing path after --output")?)
+            }
+            _ if days.is_none() => days = Some(parse_days(&arg)?),
+            _ => return Err(format!("unexpected argument {arg:?}\n\n{USAGE}")),
+        }
+    }
+
+    Ok(Args { days: days.unwrap_or_else(all_days), runs, datasets, format, output })
+}
+
+fn main() {
+    let args = match parse_args(std::env::args().skip(1)) {
+        Ok(args) => args,
+        Err(msg) => { eprintln!("{msg}"); std::process::exit(2) }
+    };
+
+    // panicking solvers are reported along with the results, rather than on stderr as well
+    std::panic::set_hook(Box::new(|_| {}));
+
+    let mut report = vec![];
+    for puzzle in SOLUTIONS.iter().filter(|p| args.days.contains(&p.day())) {
+        for &dataset in &args.datasets {
+            let input = match dataset.source(puzzle.day()).read() {
+                Ok(input) => input,
+                Err(e) => { eprintln!("day {:>2} {dataset:>6}: skipped, {e}", puzzle.day()); continue }
+            };
+            eprint!("day {:>2} {dataset:>6}: ", puzzle.day());
+            let measured = measure(*puzzle, dataset, &input, args.runs);
+            eprintln!("{}", measured.iter()
+                .map(|m| match &m.error {
+                    Some(e) => format!("{} failed, {e}", m.stage),
+                    None => format!("{} {:.2?}", m.stage, m.median),
+                })
+                .collect::<Vec<_>>()
+                .join(", "));
+            report.extend(measured);
+        }
+    }
+
+    let written = match &args.output {
+        Some(path) => std::fs::File::create(path)
+            .and_then(|mut file| write_report(&mut file, args.format, &report)),
+        None => write_report(&mut std::io::stdout().lock(), args.format, &report)
+            .and_then(|_| std::io::stdout().flush()),
+    };
+    if let Err(e) = written {
+        eprintln!("Cannot write benchmark results: {e}");
+        std::process::exit(1)
+    }
+}
@@ -1,30 +1,14 @@
-mod universe;
-mod galaxy;
-
-use crate::universe::Universe;
+use std::time::Instant;
+use advent2023::{Solution, day11::Day11};
 
 fn main() {
-    let run_part = |universe: &mut Universe, multiplier:usize| -> usize {
-        universe.expand(multiplier);
-        universe.clusters
-            .iter()
-            .enumerate()
-            .map(|(i, from)| {
-                universe.clusters
-                    .iter()
-                    .skip(i + 1)
-                    .map(|to| from.distance_to(to))
-                    .sum::<usize>()
-            })
-            .sum::<usize>()
-    };
-    let input = std::fs::read_to_string("src/bin/day11/input.txt").expect("Ops!");
+    let input = advent2023::read_input(11, std::env::args().nth(1).as_deref())
+        .unwrap_or_else(|e| { eprintln!("{e}"); std::process::exit(1) });
+    let puzzle = Day11::parse(&input);
 
-    let mut universe = input.parse::<Universe>().expect("Failed to parse Universe!");
-    let t = std::time::Instant::now();
-    println!("Part 1 - Sum of shortest paths: {} - {:?}", run_part(&mut universe,2), t.elapsed());
+    let t = Instant::now();
+    println!("Part 1 - Sum of shortest paths: {} - {:?}", Day11::part1(&puzzle), t.elapsed());
 
-    let mut universe = input.parse::<Universe>().expect("Failed to parse Universe!");
-    let t = std::time::Instant::now();
-    println!("Part 2 - Sum of shortest paths: {} - {:?}", run_part(&mut universe, 1_000_000), t.elapsed());
+    let t = Instant::now();
+    println!("Part 2 - Sum of shortest paths: {} - {:?}", Day11::part2(&puzzle), t.elapsed());
 }
@@ -1,17 +1,14 @@
-mod valley;
-mod pattern;
-
-use crate::pattern::Pattern;
-use crate::valley::Valley;
+use std::time::Instant;
+use advent2023::{Solution, day13::Day13};
 
 fn main() {
-    let input = std::fs::read_to_string("src/bin/day13/input.txt").expect("Ops!");
-    let valley = input.parse::<Valley>().expect("Ops!");
+    let input = advent2023::read_input(13, std::env::args().nth(1).as_deref())
+        .unwrap_or_else(|e| { eprintln!("{e}"); std::process::exit(1) });
+    let puzzle = Day13::parse(&input);
 
-    let t = std::time::Instant::now();
-    println!("Part 1 : {:?} - {:?}", valley.summarise_notes(Pattern::find_perfect_reflection), t.elapsed());
+    let t = Instant::now();
+    println!("Part 1 : {:?} - {:?}", Day13::part1(&puzzle), t.elapsed());
 
-    let t = std::time::Instant::now();
-    println!("Part 2 : {:?} - {:?}", valley.summarise_notes(Pattern::find_smudged_reflection), t.elapsed());
+    let t = Instant::now();
+    println!("Part 2 : {:?} - {:?}", Day13::part2(&puzzle), t.elapsed());
 }
-
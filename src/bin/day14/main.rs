@@ -1,16 +1,14 @@
-mod dish;
-
-use dish::{ReflectorDish, Direction};
+use std::time::Instant;
+use advent2023::{Solution, day14::Day14};
 
 fn main() {
-    let inp = std::fs::read_to_string("src/bin/day14/input.txt").expect("Ops!");
-    let dish = &mut inp.parse::<ReflectorDish>().unwrap_or_default();
+    let inp = advent2023::read_input(14, std::env::args().nth(1).as_deref())
+        .unwrap_or_else(|e| { eprintln!("{e}"); std::process::exit(1) });
+    let puzzle = Day14::parse(&inp);
 
-    let t = std::time::Instant::now();
-    println!("Part 1: Total load = {:?} - {:?}",dish.tilt(Direction::North),t.elapsed());
+    let t = Instant::now();
+    println!("Part 1: Total load = {:?} - {:?}", Day14::part1(&puzzle), t.elapsed());
 
-    let t = std::time::Instant::now();
-    println!("Part 2: Total load = {:?} - {:?}", dish.spin_cycle_nth(1000000000), t.elapsed()
-    );
+    let t = Instant::now();
+    println!("Part 2: Total load = {:?} - {:?}", Day14::part2(&puzzle), t.elapsed());
 }
-
@@ -1,31 +1,16 @@
-mod hash;
-mod operation;
-mod lenslib;
-
-use hash::HashLen;
-use operation::Instruction;
-use lenslib::ParabolicReflector;
+use std::time::Instant;
+use advent2023::{Solution, day15::Day15};
 
 fn main() {
-    let input = std::fs::read_to_string("./src/bin/day15/input.txt").expect("Ops");
-
-    let t = std::time::Instant::now();
-    let sum = input
-        .split([','])
-        .map(|label| label.hash_algo() )
-        .sum::<usize>();
+    let input = advent2023::read_input(15, std::env::args().nth(1).as_deref())
+        .unwrap_or_else(|e| { eprintln!("{e}"); std::process::exit(1) });
+    let puzzle = Day15::parse(&input);
 
+    let t = Instant::now();
+    let sum = Day15::part1(&puzzle);
     println!("Part 1 : Sum of Hashes = {sum} - {:?}", t.elapsed());
-    assert_eq!(sum,506869);
-
-    let t = std::time::Instant::now();
-    let mut lb = ParabolicReflector::default();
-    input
-        .split([','])
-        .map(|op| op.parse::<Instruction>().expect("Cannot parse instruction"))
-        .map(|op| lb.initiation(&op))
-        .last();
 
-    println!("Part 2 Focusing power: {} - {:?}",lb.focusing_power(), t.elapsed());
-    assert_eq!(lb.focusing_power(),271384);
+    let t = Instant::now();
+    let power = Day15::part2(&puzzle);
+    println!("Part 2 Focusing power: {power} - {:?}", t.elapsed());
 }
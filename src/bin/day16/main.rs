@@ -1,27 +1,16 @@
-mod direction;
-mod cavern;
-
-use crate::cavern::{Cavern,entry_points};
-use crate::direction::Direction as D;
+use std::time::Instant;
+use advent2023::{Solution, day16::Day16};
 
 fn main() {
-    let inp = std::fs::read_to_string("src/bin/day16/input.txt").expect("Ops!");
-    let mut cavern = inp.parse::<Cavern>().unwrap();
+    let inp = advent2023::read_input(16, std::env::args().nth(1).as_deref())
+        .unwrap_or_else(|e| { eprintln!("{e}"); std::process::exit(1) });
+    let puzzle = Day16::parse(&inp);
 
-    let t = std::time::Instant::now();
-    cavern.energise(0, D::Right);
-    println!("Part 1 : Cavern Energy = {:?} - {:?}", cavern.measure_energy(), t.elapsed());
-    assert_eq!(cavern.measure_energy(), 6902);
+    let t = Instant::now();
+    let energy = Day16::part1(&puzzle);
+    println!("Part 1 : Cavern Energy = {:?} - {:?}", energy, t.elapsed());
 
-    let t = std::time::Instant::now();
-    let m = entry_points(cavern.width, cavern.lines)
-            .map(|(idx,dir)| {
-                cavern.energise(idx,dir);
-                cavern.measure_energy()
-            })
-            .max();
+    let t = Instant::now();
+    let m = Day16::part2(&puzzle);
     println!("Part 2 : Max Energy = {:?} - {:?}", m, t.elapsed());
-    assert_eq!(m,Some(7697));
 }
-
-
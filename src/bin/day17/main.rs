@@ -1,33 +1,42 @@
-use std::ops::Range;
-use crate::{
-    block::{Heat, Step},
-    citymap::CityMap,
-    direction::Direction as D
-};
-
-mod citymap;
-mod direction;
-mod crucible;
-mod block;
-mod path;
+use std::time::Instant;
+use advent2023::{Solution, day17::{Day17, compare_searches, render, rules::MovementRules, direction::Direction}};
 
 fn main() {
-    let input = std::fs::read_to_string("src/bin/day17/input.txt").expect("File Not Found!");
-    let map = input.parse::<CityMap>().expect("ops");
-
-    let total_heat_loss = |rng: Range<Step>| -> Option<Heat> {
-        map.get_crucible(0, D::Right)
-            .find_path_to(map.len()-1, rng)
-            .map(|path| path.total_heat_loss() )
-    };
+    let input = advent2023::read_input(17, std::env::args().nth(1).as_deref())
+        .unwrap_or_else(|e| { eprintln!("{e}"); std::process::exit(1) });
+    let puzzle = Day17::parse(&input);
 
-    let t = std::time::Instant::now();
-    let loss = total_heat_loss(0..3);
+    let t = Instant::now();
+    let loss = Day17::part1(&puzzle);
     println!("Part 1: {:?} = {:?}", loss, t.elapsed());
-    assert_eq!(loss, Some(1008), "Part 1 result doesn't match");
 
-    let t = std::time::Instant::now();
-    let loss = total_heat_loss(4..10);
+    let t = Instant::now();
+    let loss = Day17::part2(&puzzle);
     println!("Part 2: {:?} = {:?}", loss, t.elapsed());
-    assert_eq!(loss, Some(1210), "Part 2 result doesn't match");
+
+    // compare the work done by each search strategy and queue, against plain Dijkstra over a binary heap
+    for (part, rules) in [(1, MovementRules::from(0..3)), (2, MovementRules::from(4..10))] {
+        println!("\nPart {part}: {:<8} | {:<11} | {:>4} | {:>9} | {:>9} | {:>10}", "search", "queue", "heat", "expanded", "queued", "time");
+        let runs = compare_searches(&puzzle, &rules);
+        let baseline = runs[0].stats.expanded as f64;
+        for run in runs {
+            println!(
+                "        {:<8} | {:<11} | {:>4} | {:>9} | {:>9} | {:>10.2?} ({:.0}% expansions)",
+                format!("{:?}", run.strategy), format!("{:?}", run.queue), run.heat.unwrap_or(0),
+                run.stats.expanded, run.stats.queued, run.elapsed, 100.0 * run.stats.expanded as f64 / baseline
+            );
+        }
+    }
+
+    // given an output directory, draw the path of each part over the city's heat map
+    if let Some(dir) = std::env::args().nth(2) {
+        for (part, rules) in [(1, MovementRules::from(0..3)), (2, MovementRules::from(4..10))] {
+            let Some(path) = puzzle.get_crucible(0, Direction::Right).find_path_to(puzzle.len()-1, rules) else { continue };
+            let file = std::path::Path::new(&dir).join(format!("day17_part{part}"));
+            render::svg(&puzzle, &path, 8.0).save(file.with_extension("svg"))
+                .and_then(|_| render::raster(&puzzle, &path, 4).save(file.with_extension("png")))
+                .unwrap_or_else(|e| eprintln!("Cannot write {}: {e}", file.display()));
+            println!("Part {part}: path rendered to {}.svg/.png", file.display());
+        }
+    }
 }
@@ -1,45 +1,36 @@
-mod digging_plan;
-mod instruction;
-mod lagoon;
-mod position;
-
-use digging_plan::DigPlan;
-use lagoon::{Digger, Lagoon};
-use position::Position;
-use crate::instruction::Instruction;
+use std::time::Instant;
+use advent2023::{Solution, day18::{Day18, Engine, lagoon_total, render}};
 
 fn main() {
-    type FnEncode = fn(&Instruction) -> Instruction;
-
-    let plan = match std::fs::read_to_string("./src/bin/day18/input.txt")
-        .expect("Cannot Find File")
-        .parse::<DigPlan>() {
-            Ok(plan) => plan,
-            Err(e) => panic!("{}",e),
+    let input = advent2023::read_input(18, std::env::args().nth(1).as_deref())
+        .unwrap_or_else(|e| { eprintln!("{e}"); std::process::exit(1) });
+    let puzzle = Day18::parse(&input);
+    let engine = std::env::args().nth(2)
+        .map(|e| e.parse::<Engine>())
+        .transpose()
+        .unwrap_or_else(|e| { eprintln!("{e}"); std::process::exit(1) })
+        .unwrap_or_default();
+    println!("Engine: {engine:?}");
+
+    for part in [1, 2] {
+        // the trench decoded from the colours may be left open or run into itself
+        let trench = match if part == 1 { Ok(&puzzle.dug) } else { puzzle.decoded.as_ref() } {
+            Ok(trench) => trench,
+            Err(e) => { eprintln!("Part {part}: {e}"); continue }
         };
-
-    let dig_lagoon =
-        |enc: FnEncode| -> usize {
-            let mut lagoon = Lagoon::default();
-            let mut digger = Digger::new(Position(0, 0));
-
-            let t = std::time::Instant::now();
-            let total = plan
-                .iter()
-                .map(|ins| digger.dig(&mut lagoon, &enc(ins)))
-                .sum::<usize>();
-
-            let area = lagoon.calculate_area();
-            println!("\nPart 1:\n\tLagoon Periphery {}\n\tLagoon area = {}\nTotal: {} - {:?}",
-                total, area, total + area,t.elapsed()
-            );
-
-            total + area
-    };
-
-    let pass_through: FnEncode = |i| { i.clone() };
-    let encode_rgb: FnEncode =  |i| { i.decode_rgb() };
-
-    assert_eq!(40714, dig_lagoon(pass_through));
-    assert_eq!(129849166997110, dig_lagoon(encode_rgb));
+        println!("Part {part}: {:?} trench", trench.winding());
+        let t = Instant::now();
+        let total = lagoon_total(trench, engine);
+        println!("Part {part}: Lagoon total = {} - {:?}", total, t.elapsed());
+
+        // given an output directory, draw the lagoon in the colours of its trench
+        if let Some(dir) = std::env::args().nth(3) {
+            let instructions = trench.plan().iter().cloned().collect::<Vec<_>>();
+            let file = std::path::Path::new(&dir).join(format!("day18_part{part}"));
+            render::svg(&instructions, 1000.0).save(file.with_extension("svg"))
+                .and_then(|_| render::raster(&instructions, 1000).save(file.with_extension("png")))
+                .unwrap_or_else(|e| eprintln!("Cannot write {}: {e}", file.display()));
+            println!("Part {part}: lagoon rendered to {}.svg/.png", file.display());
+        }
+    }
 }
@@ -1,47 +1,57 @@
-mod workflow;
-mod rule;
-mod part;
-mod system;
-
-use std::rc::Rc;
-use crate::{system::SortingSystem, part::{Part,Unit}, rule::Action};
+use std::time::Instant;
+use advent2023::{Solution, day19::{Day19, analysis, optimise::optimise, part::ALL_RATINGS, rule::Action, tree::DecisionTree}};
 
 fn main() {
-    let (parts, system) = parse_puzzle_data("src/bin/day19/input.txt");
-
-    let t = std::time::Instant::now();
-    let sum = parts.iter()
-        .filter(|&&part|
-            system.process_part(part, "in") == Some(Action::Accept)
-        )
-        .map(|part| part.sum())
-        .sum::<Unit>();
+    let input = advent2023::read_input(19, std::env::args().nth(1).as_deref())
+        .unwrap_or_else(|e| { eprintln!("{e}"); std::process::exit(1) });
+    let puzzle = Day19::parse(&input);
 
+    let t = Instant::now();
+    let sum = Day19::part1(&puzzle);
     println!("Part 1: Sum of approved parts: {sum} ({:?})", t.elapsed());
-    assert_eq!(sum,287_054);
 
-    let t = std::time::Instant::now();
-    let sum = system.total_combinations("in", &[1..4001, 1..4001, 1..4001, 1..4001]);
+    let t = Instant::now();
+    let sum = Day19::part2(&puzzle);
     println!("Part 2: Total combinations: {sum}, ({:?})", t.elapsed());
-    assert_eq!(sum,131_619_440_296_497);
-}
-
-fn parse_puzzle_data(file: &str) -> (Rc<[Part]>, SortingSystem) {
-    let inp = std::fs::read_to_string(file)
-        .expect("cannot load data file");
-    let mut split = inp.split("\n\n");
-    let wfs = split
-        .next()
-        .unwrap()
-        .parse::<SortingSystem>()
-        .expect("Failed to parse workflows");
-
-    let parts = split
-        .next()
-        .unwrap()
-        .lines()
-        .map(|line| line.parse::<Part>().expect("msg") )
-        .collect::<Rc<[Part]>>();
 
-    (parts,wfs)
+    // with `explain` after the input file, show the rules each part went through
+    if std::env::args().nth(2).as_deref() == Some("explain") {
+        let (parts, system) = &puzzle;
+        let tree = DecisionTree::compile(system, "in")
+            .unwrap_or_else(|e| { eprintln!("{e}"); std::process::exit(1) });
+        println!("\nDecision tree of {} nodes", tree.len());
+        parts.iter().for_each(|&part| println!("{}", tree.explain(part)));
+    }
+    // with `regions` after the input file, list the boxes of ratings accepted and the rules that led there
+    if std::env::args().nth(2).as_deref() == Some("regions") {
+        let (_, system) = &puzzle;
+        let all = [1..4001, 1..4001, 1..4001, 1..4001];
+        let regions = system.regions("in", &all);
+        println!("\n{} boxes, {} of them accepted", regions.len(), regions.iter().filter(|r| r.action == Action::Accept).count());
+        for r in regions.iter().filter(|r| r.action == Action::Accept) {
+            let path = r.path.iter().map(|o| format!("{}:{}", o.workflow, o.rule)).collect::<Vec<_>>();
+            println!("{:?} {} => {}", r.ranges, r.combinations(), path.join(" -> "));
+        }
+        for (wf, index) in system.unused_rules("in", &all) {
+            println!("Rule #{} of workflow [{wf}] is never used", index + 1);
+        }
+    }
+    // with `check` after the input file, list problems and simplifications found in the workflows
+    if std::env::args().nth(2).as_deref() == Some("check") {
+        let (_, system) = &puzzle;
+        let diagnostics = analysis::analyse(system, "in");
+        println!("\n{} diagnostics", diagnostics.len());
+        diagnostics.iter().for_each(|d| println!("{d}"));
+    }
+    // with `optimise` after the input file, rewrite the workflows and check they sort parts just the same
+    if std::env::args().nth(2).as_deref() == Some("optimise") {
+        let (parts, system) = &puzzle;
+        let t = Instant::now();
+        let optimised = optimise(system, "in");
+        println!("\n{optimised}\n\nOptimised {} workflows into {} ({:?})", system.workflows().count(), optimised.workflows().count(), t.elapsed());
+
+        let same = parts.iter().all(|&part| system.process_part(part, "in") == optimised.process_part(part, "in"))
+            && system.total_combinations("in", &ALL_RATINGS) == optimised.total_combinations("in", &ALL_RATINGS);
+        println!("Parts sorted the same: {same}");
+    }
 }
@@ -0,0 +1,19 @@
+use std::time::Instant;
+use advent2023::{Solution, day20::{Day20, MAX_PRESSES, presses_until_low}};
+
+fn main() {
+    let input = advent2023::read_input(20, std::env::args().nth(1).as_deref())
+        .unwrap_or_else(|e| { eprintln!("{e}"); std::process::exit(1) });
+    let puzzle = Day20::parse(&input);
+
+    let t = Instant::now();
+    let answer = Day20::part1(&puzzle);
+    println!("Part 1: Low pulses x high pulses: {answer} ({:?})", t.elapsed());
+
+    let t = Instant::now();
+    // networks without a single conjunction feeding rx, such as the samples, have no answer
+    match presses_until_low(&mut puzzle.clone(), "rx", MAX_PRESSES) {
+        Ok(answer) => println!("Part 2: Button presses until rx receives a low pulse: {answer} ({:?})", t.elapsed()),
+        Err(e) => eprintln!("Part 2: {e}"),
+    }
+}
@@ -0,0 +1,19 @@
+use std::time::Instant;
+use advent2023::{Solution, day21::{Day21, walker::reachable_extrapolated}};
+
+fn main() {
+    let input = advent2023::read_input(21, std::env::args().nth(1).as_deref())
+        .unwrap_or_else(|e| { eprintln!("{e}"); std::process::exit(1) });
+    let puzzle = Day21::parse(&input);
+
+    let t = Instant::now();
+    let answer = Day21::part1(&puzzle);
+    println!("Part 1: Garden plots reached in 64 steps: {answer} ({:?})", t.elapsed());
+
+    let t = Instant::now();
+    // only maps shaped like the real input can be extrapolated
+    match reachable_extrapolated(&puzzle, 26_501_365) {
+        Ok(answer) => println!("Part 2: Garden plots reached in 26501365 steps: {answer} ({:?})", t.elapsed()),
+        Err(e) => eprintln!("Part 2: {e}"),
+    }
+}
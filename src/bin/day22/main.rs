@@ -0,0 +1,16 @@
+use std::time::Instant;
+use advent2023::{Solution, day22::Day22};
+
+fn main() {
+    let input = advent2023::read_input(22, std::env::args().nth(1).as_deref())
+        .unwrap_or_else(|e| { eprintln!("{e}"); std::process::exit(1) });
+    let puzzle = Day22::parse(&input);
+
+    let t = Instant::now();
+    let answer = Day22::part1(&puzzle);
+    println!("Part 1: Bricks safe to disintegrate: {answer} ({:?})", t.elapsed());
+
+    let t = Instant::now();
+    let answer = Day22::part2(&puzzle);
+    println!("Part 2: Sum of bricks falling in chain reactions: {answer} ({:?})", t.elapsed());
+}
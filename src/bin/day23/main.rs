@@ -0,0 +1,16 @@
+use std::time::Instant;
+use advent2023::{Solution, day23::Day23};
+
+fn main() {
+    let input = advent2023::read_input(23, std::env::args().nth(1).as_deref())
+        .unwrap_or_else(|e| { eprintln!("{e}"); std::process::exit(1) });
+    let puzzle = Day23::parse(&input);
+
+    let t = Instant::now();
+    let answer = Day23::part1(&puzzle);
+    println!("Part 1: Longest hike on slippery slopes: {answer} ({:?})", t.elapsed());
+
+    let t = Instant::now();
+    let answer = Day23::part2(&puzzle);
+    println!("Part 2: Longest hike on dry slopes: {answer} ({:?})", t.elapsed());
+}
@@ -0,0 +1,16 @@
+use std::time::Instant;
+use advent2023::{Solution, day24::Day24};
+
+fn main() {
+    let input = advent2023::read_input(24, std::env::args().nth(1).as_deref())
+        .unwrap_or_else(|e| { eprintln!("{e}"); std::process::exit(1) });
+    let puzzle = Day24::parse(&input);
+
+    let t = Instant::now();
+    let answer = Day24::part1(&puzzle);
+    println!("Part 1: Hailstone paths crossing in the test area: {answer} ({:?})", t.elapsed());
+
+    let t = Instant::now();
+    let answer = Day24::part2(&puzzle);
+    println!("Part 2: Sum of the rock's starting coordinates: {answer} ({:?})", t.elapsed());
+}
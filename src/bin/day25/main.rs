@@ -0,0 +1,16 @@
+use std::time::Instant;
+use advent2023::{Solution, day25::Day25};
+
+fn main() {
+    let input = advent2023::read_input(25, std::env::args().nth(1).as_deref())
+        .unwrap_or_else(|e| { eprintln!("{e}"); std::process::exit(1) });
+    let puzzle = Day25::parse(&input);
+
+    let t = Instant::now();
+    let answer = Day25::part1(&puzzle);
+    println!("Part 1: Product of the two group sizes: {answer} ({:?})", t.elapsed());
+
+    let t = Instant::now();
+    let answer = Day25::part2(&puzzle);
+    println!("Part 2: Final star: {answer} ({:?})", t.elapsed());
+}
@@ -1,28 +1,14 @@
-
-mod engine;
-mod parts;
-
-use std::time;
-use std::time::*;
-use crate::engine::*;
+use std::time::Instant;
+use advent2023::{Solution, day3::Day3};
 
 fn main() {
-    let input = std::fs::read_to_string("src/bin/day3/input.txt").expect("Ops!");
-    let es = input.parse::<EngineSchematic>().expect("Ops!");
+    let input = advent2023::read_input(3, std::env::args().nth(1).as_deref())
+        .unwrap_or_else(|e| { eprintln!("{e}"); std::process::exit(1) });
+    let puzzle = Day3::parse(&input);
 
     let t = Instant::now();
-    let sum = es
-        .part_numbers()
-        .map(|pn| pn.number)
-        .sum::<u32>();
-
-    println!("Par 1 - Sum: {sum} - {:?}", t.elapsed());
+    println!("Par 1 - Sum: {} - {:?}", Day3::part1(&puzzle), t.elapsed());
 
-    let t = time::Instant::now();
-    let sum = es
-        .get_gears_part_numbers('*')
-        .map(|d| d.iter().map(|d| d.number).product::<u32>())
-        .sum::<u32>();
-
-    println!("Par 1 - Sum: {sum} - {:?}", t.elapsed());
+    let t = Instant::now();
+    println!("Par 2 - Sum: {} - {:?}", Day3::part2(&puzzle), t.elapsed());
 }
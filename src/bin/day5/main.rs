@@ -1,36 +1,16 @@
-mod map;
-mod mapping;
-mod pipeline;
-mod error;
-
 use std::time::Instant;
-use map::MapType;
-use pipeline::{ Run, Pipeline, Seeds};
+use advent2023::{Solution, day5::Day5};
 
-fn main() -> Result<(),()> {
-    let input = std::fs::read_to_string("src/bin/day5/input.txt").expect("Ops!");
-    let seeds = input.parse::<Seeds>().map_err(|e| panic!("{e}"))?;
-    let pipeline = input.parse::<Pipeline>().map_err(|e| panic!("{e:?}"))?;
+fn main() {
+    let input = advent2023::read_input(5, std::env::args().nth(1).as_deref())
+        .unwrap_or_else(|e| { eprintln!("{e}"); std::process::exit(1) });
+    let puzzle = Day5::parse(&input);
 
     let t = Instant::now();
-    let min = seeds.iter()
-        .map(|&seed|
-            pipeline.run(seed, MapType::Seed)
-        )
-        .min();
-
+    let min = Day5::part1(&puzzle);
     println!("Part 1, min: {:?} - {:?}",min, t.elapsed());
-    assert_eq!(min, Some(388_071_289));
 
     let t = Instant::now();
-    let ranges = pipeline.run(seeds.get_ranges(), MapType::Seed);
-    let min = ranges
-        .iter()
-        .min_by_key(|r| r.start)
-        .unwrap();
-
-    println!("Part 2, min: {:?} - {:?}",min.start, t.elapsed());
-    assert_eq!(min.start, 84_206_669);
-
-    Ok(())
+    let min = Day5::part2(&puzzle);
+    println!("Part 2, min: {:?} - {:?}",min, t.elapsed());
 }
@@ -1,32 +1,14 @@
-mod sequence;
-mod iterator;
-
-use crate::sequence::{Sequence,Number};
+use std::time::Instant;
+use advent2023::{Solution, day9::Day9};
 
 fn main() {
-    let input = std::fs::read_to_string("src/bin/day9/input.txt").expect("Ops!");
-    let mut seqs = input
-        .lines()
-        .map(|line| line
-            .parse::<Sequence>()
-            .unwrap_or_else(|e| panic!("Ops! {} -> {:?}",e, line))
-        )
-        .collect::<Vec<_>>();
-
-    let t = std::time::Instant::now();
-    let sum = seqs
-        .iter_mut()
-        .map(|seq| seq.iter_forward().next().unwrap() )
-        .sum::<Number>();
-
-    println!("Part 1 - Sum of forward predictions: {sum} - {:?}", t.elapsed());
-
-    let t = std::time::Instant::now();
-    let sum = seqs
-        .iter_mut()
-        .map(|seq| seq.iter_backward().next().unwrap() )
-        .sum::<Number>();
+    let input = advent2023::read_input(9, std::env::args().nth(1).as_deref())
+        .unwrap_or_else(|e| { eprintln!("{e}"); std::process::exit(1) });
+    let puzzle = Day9::parse(&input);
 
-    println!("Part 2 - Sum of backward predictions: {sum} - {:?}", t.elapsed());
+    let t = Instant::now();
+    println!("Part 1 - Sum of forward predictions: {} - {:?}", Day9::part1(&puzzle), t.elapsed());
 
+    let t = Instant::now();
+    println!("Part 2 - Sum of backward predictions: {} - {:?}", Day9::part2(&puzzle), t.elapsed());
 }
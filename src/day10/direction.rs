@@ -1,8 +1,10 @@
+pub use crate::grid::Direction;
 
-#[derive(Debug, Copy, Clone, PartialEq)]
-pub(crate) enum Direction { Up, Right, Down, Left }
+pub trait PipeExit: Sized {
+    fn pipe_exit(&self, c: char) -> Option<Self>;
+}
 
-impl Direction {
+impl PipeExit for Direction {
 
     // The pipes are arranged in a two-dimensional grid of tiles:
     //
@@ -16,7 +18,7 @@ impl Direction {
     // S is the starting position of the animal; there is a pipe on this tile, but your sketch doesn't show what shape the pipe has.
     //
     // Given (a) a pipe to step on and (b) current direction derive the new direction if valid otherwise None
-    pub(crate) fn pipe_exit(&self, c: char) -> Option<Self> {
+    fn pipe_exit(&self, c: char) -> Option<Self> {
         use Direction as D;
         match (self, c) {
             (D::Left|D::Right , '-') => Some(*self),
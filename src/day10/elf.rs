@@ -1,6 +1,8 @@
-use crate::{
+use crate::grid::Coord;
+use crate::day10::{
     field::Field,
     direction::{
+        PipeExit,
         Direction,
         Direction::{Down, Left, Right, Up}
     },
@@ -11,26 +13,26 @@ use crate::{
 };
 
 #[derive(Debug,PartialEq)]
-pub(crate) struct Elf<'a> {
-    pub(crate) field: &'a Field,
-    pub(crate) pos: (usize, usize),
-    pub(crate) dir: Direction,
+pub struct Elf<'a> {
+    pub field: &'a Field,
+    pub pos: (usize, usize),
+    pub dir: Direction,
 }
 
 impl Elf<'_> {
-    pub(crate) fn traverse_pipes(&mut self, finish:char) -> PipeLoop {
+    pub fn traverse_pipes(&mut self, finish:char) -> PipeLoop {
         let mut path = self.take_while(|(p, _)| finish.ne(p)).collect::<Vec<_>>();
         path.push(('S', self.field.start));
         PipeLoop { path }
     }
-    pub(crate) fn valid_directions(&self) -> Vec<Direction> {
-        [
-            self.field.get_pipe((self.pos.0-1,self.pos.1)).and_then(|p| Left.pipe_exit(p)),
-            self.field.get_pipe((self.pos.0+1,self.pos.1)).and_then(|p| Right.pipe_exit(p)),
-            self.field.get_pipe((self.pos.0,self.pos.1-1)).and_then(|p| Up.pipe_exit(p)),
-            self.field.get_pipe((self.pos.0,self.pos.1+1)).and_then(|p| Down.pipe_exit(p))
-        ].iter()
-            .filter_map(|&d| d)
+    pub fn valid_directions(&self) -> Vec<Direction> {
+        [Left, Right, Up, Down]
+            .into_iter()
+            .filter_map(|dir|
+                self.field.data.move_from(Coord::from(self.pos), dir)
+                    .and_then(|c| self.field.get_pipe((c.x, c.y)))
+                    .and_then(|p| dir.pipe_exit(p))
+            )
             .collect::<Vec<_>>()
     }
 }
@@ -39,13 +41,9 @@ impl Iterator for Elf<'_> {
     type Item = Step;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let pos = match self.dir {
-            Up => (self.pos.0, self.pos.1-1),
-            Right => (self.pos.0+1, self.pos.1),
-            Down => (self.pos.0, self.pos.1+1),
-            Left => (self.pos.0-1, self.pos.1),
-        };
         // have we landed on a valid position ?
+        let pos = self.field.data.move_from(Coord::from(self.pos), self.dir)
+            .map(|c| (c.x, c.y))?;
         self.field.get_pipe(pos)
             .and_then(|p|
                 // Can we enter the new pipe from current direction ?
@@ -0,0 +1,52 @@
+use thiserror::Error;
+use crate::grid::{Coord, Grid, GridError};
+use crate::day10::direction::Direction::*;
+use crate::day10::elf::Elf;
+
+#[derive(Debug, PartialEq, Error)]
+pub enum FieldError {
+    #[error(transparent)]
+    Grid(#[from] GridError),
+    #[error("Field has no starting position {0:?}")]
+    MissingStart(char),
+}
+
+#[derive(Debug,PartialEq)]
+pub struct Field {
+    pub data: Grid<char>,
+    pub start: (usize, usize)
+}
+
+impl Field {
+    pub fn connects_right_with(&self, pos:(usize, usize)) -> Option<&char> {
+        self.data.row(pos.1)[pos.0+1..]
+            .iter()
+            .take_while(|c| '.'.ne(c))
+            .find(|&c| ['F','J','L','7','|'].contains(c))
+    }
+    pub fn connects_left_with(&self, pos:(usize, usize)) -> Option<&char> {
+        self.data.row(pos.1)[..pos.0]
+            .iter()
+            .rev()
+            .take_while(|c| '.'.ne(c))
+            .find(|&c| ['F','J','L','7','|'].contains(c))
+    }
+
+    pub fn get_pipe(&self, pos: (usize, usize)) -> Option<char> {
+        self.data.get(Coord::from(pos)).copied()
+    }
+    pub fn get_walking_elf(&self, start: Option<(usize, usize)>) -> Elf<'_> {
+        Elf {
+            field: self,
+            pos: start.unwrap_or(self.start),
+            dir: Right,
+        }
+    }
+    pub fn parse(s: &str, start: char) -> Result<Field, FieldError> {
+        let data = s.parse::<Grid<char>>()?;
+        let start_pos = data.iter().position(|c| start.eq(c)).ok_or(FieldError::MissingStart(start))?;
+        let Coord { x, y } = data.coord_of(start_pos);
+
+        Ok(Field { data, start: (x, y) })
+    }
+}
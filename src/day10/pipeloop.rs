@@ -1,17 +1,20 @@
 use std::cmp::Ordering;
-use crate::field::Field;
+use crate::day10::field::Field;
 
-pub(crate) type Step = (char, (usize, usize));
+pub type Step = (char, (usize, usize));
 
-pub(crate) struct PipeLoop {
-    pub(crate) path: Vec<Step>
+pub struct PipeLoop {
+    pub path: Vec<Step>
 }
 
 impl PipeLoop {
-    pub(crate) fn len(&self) -> usize {
+    pub fn len(&self) -> usize {
         self.path.len()
     }
-    pub(crate) fn order_by_scan_lines(&mut self) -> impl Iterator<Item=&mut [Step]> + '_ {
+    pub fn is_empty(&self) -> bool {
+        self.path.is_empty()
+    }
+    pub fn order_by_scan_lines(&mut self) -> impl Iterator<Item=&mut [Step]> + '_ {
         self.path.sort_by(|(_, a), (_, b)|
             match a.1.cmp(&b.1) {
                 Ordering::Equal => a.0.cmp(&b.0),
@@ -22,7 +25,7 @@ impl PipeLoop {
     }
 }
 
-pub(crate) trait PipeLoopCutter {
+pub trait PipeLoopCutter {
     type Output;
     fn get_valid_pairs(&mut self, f: &Field) -> impl Iterator<Item=&Self::Output>;
 }
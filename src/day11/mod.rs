@@ -0,0 +1,41 @@
+pub mod universe;
+pub mod galaxy;
+
+use universe::Universe;
+use crate::Solution;
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    type Input = Universe;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Universe {
+        input.parse::<Universe>().expect("Failed to parse Universe!")
+    }
+    fn part1(universe: &Universe) -> usize {
+        sum_of_shortest_paths(universe, 2)
+    }
+    fn part2(universe: &Universe) -> usize {
+        sum_of_shortest_paths(universe, 1_000_000)
+    }
+}
+
+fn sum_of_shortest_paths(universe: &Universe, multiplier: usize) -> usize {
+    let mut universe = universe.clone();
+
+    universe.expand(multiplier);
+    universe.clusters
+        .iter()
+        .enumerate()
+        .map(|(i, from)| {
+            universe.clusters
+                .iter()
+                .skip(i + 1)
+                .map(|to| from.distance_to(to))
+                .sum::<usize>()
+        })
+        .sum::<usize>()
+}
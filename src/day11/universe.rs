@@ -1,14 +1,15 @@
 use std::ops::RangeInclusive;
 use std::str::FromStr;
-use crate::galaxy::Galaxy;
+use crate::grid::{Grid, GridError};
+use crate::day11::galaxy::Galaxy;
 
-#[derive(Debug,PartialEq)]
-pub(crate) struct Universe {
-    pub(crate) clusters: Vec<Galaxy>
+#[derive(Debug,Clone,PartialEq)]
+pub struct Universe {
+    pub clusters: Vec<Galaxy>
 }
 
 impl Universe {
-    pub(crate) fn expand(&mut self, multiplier: usize) -> &Self {
+    pub fn expand(&mut self, multiplier: usize) -> &Self {
         let expand = if multiplier > 1 { multiplier - 1 } else { 1 };
 
         let (mut x_gap, mut y_gap) = (vec![], vec![]);
@@ -47,7 +48,7 @@ impl Universe {
         self
     }
 
-    pub(crate) fn extract_gaps(seq: &[usize]) -> impl Iterator<Item=RangeInclusive<usize>> + '_ {
+    pub fn extract_gaps(seq: &[usize]) -> impl Iterator<Item=RangeInclusive<usize>> + '_ {
         seq.windows(2)
             .filter_map(|pair| {
                 if pair[1] - pair[0] > 1 {
@@ -60,23 +61,19 @@ impl Universe {
 }
 
 impl FromStr for Universe {
-    type Err = ();
+    type Err = GridError;
 
     fn from_str(input: &str) -> Result<Self, Self::Err> {
-        let mut clusters = vec![];
+        let image = Grid::parse_with(input, |c| matches!(c, '.' | '#').then_some(c))?;
 
-        input
-            .lines()
+        let clusters = image.iter()
             .enumerate()
-            .for_each(|(y, line)| {
-                line.chars()
-                    .enumerate()
-                    .filter(|(_, c)| '#'.eq(c))
-                    .map(|(x, _)| {
-                        Galaxy { pos: (x, y) }
-                    })
-                    .collect_into(&mut clusters);
-            });
+            .filter(|(_, c)| '#'.eq(*c))
+            .map(|(idx, _)| {
+                let pos = image.coord_of(idx);
+                Galaxy { pos: (pos.x, pos.y) }
+            })
+            .collect();
 
         Ok( Universe { clusters })
     }
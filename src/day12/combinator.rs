@@ -4,12 +4,12 @@ use std::collections::HashMap;
 type Cache<'a> = RefCell<HashMap<(String, &'a [usize]),usize>>;
 
 #[derive(Default)]
-pub(crate) struct Combinator<'a> {
+pub struct Combinator<'a> {
     mem: Cache<'a>
 }
 
 impl<'a> Combinator<'a> {
-    pub(crate) fn get_combinations(&self, inp: &str, count: &'a [usize]) -> usize {
+    pub fn get_combinations(&self, inp: &str, count: &'a [usize]) -> usize {
         let mut iter = inp.chars();
 
         // println!("{:?}", (&inp, &count, inp.len(), &count.iter().sum::<usize>()));
@@ -72,7 +72,7 @@ impl<'a> Combinator<'a> {
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::parse;
+    use crate::day12::parse;
 
     #[test]
     fn test_parse_combinations() {
@@ -0,0 +1,56 @@
+pub mod combinator;
+
+use rayon::prelude::*;
+use combinator::*;
+use crate::Solution;
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    // records are unfolded a different number of times per part,
+    // hence each part parses the raw input itself
+    type Input = String;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> String {
+        input.to_string()
+    }
+    fn part1(input: &String) -> usize {
+        sum_of_arrangements(&parse(input,1))
+    }
+    fn part2(input: &String) -> usize {
+        sum_of_arrangements(&parse(input,5))
+    }
+}
+
+fn sum_of_arrangements(arr: &[(String,Vec<usize>)]) -> usize {
+    arr.par_iter()
+        .map(|(broken, record)| {
+            Combinator::default().get_combinations(broken, record)
+        })
+        .sum::<usize>()
+}
+
+pub fn parse(input:&str, repetitions: usize) -> Vec<(String, Vec<usize>)> {
+    input.lines()
+        .map(|line| {
+            let mut split = line.split_ascii_whitespace();
+            let mut broken_rec = split.next()
+                .map(|s|{ let mut t = String::from(s); if repetitions > 1 { t.push('?') } t })
+                .unwrap()
+                .repeat(repetitions);
+            if repetitions > 1 { broken_rec.pop(); }
+            let rec = split.next()
+                .map(|s|{
+                    s.split(',')
+                        .map(|n| n.parse::<usize>().expect("Ops!")).collect::<Vec<_>>()
+                })
+                .unwrap_or_default()
+                .repeat(repetitions);
+
+            ( broken_rec, rec )
+        })
+        .collect::<Vec<_>>()
+}
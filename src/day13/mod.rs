@@ -0,0 +1,25 @@
+pub mod valley;
+pub mod pattern;
+
+use pattern::Pattern;
+use valley::Valley;
+use crate::Solution;
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    type Input = Valley;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Valley {
+        input.parse::<Valley>().expect("Ops!")
+    }
+    fn part1(valley: &Valley) -> usize {
+        valley.summarise_notes(Pattern::find_perfect_reflection)
+    }
+    fn part2(valley: &Valley) -> usize {
+        valley.summarise_notes(Pattern::find_smudged_reflection)
+    }
+}
@@ -1,12 +1,13 @@
 use std::fmt::Debug;
 use std::rc::Rc;
 use std::str::FromStr;
+use crate::grid::{Grid, GridError};
 
-pub(crate) type Reflection = usize;
+pub type Reflection = usize;
 
-pub(crate) struct Pattern {
-    pub(crate) p: Rc<[String]>,
-    pub(crate) t: Rc<[String]>
+pub struct Pattern {
+    pub p: Rc<[String]>,
+    pub t: Rc<[String]>
 }
 
 impl Pattern {
@@ -18,7 +19,7 @@ impl Pattern {
         li.take_while(|&lc| ri.next() == Some(lc)).count()
     }
 
-    pub(crate) fn find_smudged_reflection(pat: &[String]) -> impl Iterator<Item=Reflection> + '_ {
+    pub fn find_smudged_reflection(pat: &[String]) -> impl Iterator<Item=Reflection> + '_ {
         let (width, height) = (pat[0].len(), pat.len());
         let mut smudge_counter = vec![0; width];
 
@@ -39,7 +40,7 @@ impl Pattern {
             })
     }
 
-    pub(crate) fn find_perfect_reflection(pat: &[String]) -> impl Iterator<Item=Reflection> + '_ {
+    pub fn find_perfect_reflection(pat: &[String]) -> impl Iterator<Item=Reflection> + '_ {
         let width = pat[0].len();
 
         (1..width)
@@ -49,20 +50,15 @@ impl Pattern {
                     .all(|r| idx == r || idx + r == width)
             )
     }
-    fn transpose(p: &[String]) -> impl Iterator<Item=String> + '_ {
-        (0..p[0].len())
-            .map(move |col| {
-                p.iter().map(|line| line.as_bytes()[col] as char).collect::<String>()
-            })
-    }
 }
 
 impl FromStr for Pattern {
-    type Err = ();
+    type Err = GridError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let p = s.lines().map(String::from).collect::<Rc<[String]>>();
-        let t = Pattern::transpose(&p).collect::<Rc<[String]>>();
+        let grid = Grid::parse_with(s, |c| matches!(c, '.' | '#').then_some(c))?;
+        let p = grid.rows().map(|row| row.iter().collect::<String>()).collect::<Rc<[String]>>();
+        let t = grid.columns().map(|col| col.collect::<String>()).collect::<Rc<[String]>>();
 
         Ok(Pattern { p, t })
     }
@@ -95,8 +91,8 @@ impl Debug for Pattern {
 
 #[cfg(test)]
 mod test {
-    use crate::pattern::Pattern;
-    use crate::valley::Valley;
+    use crate::day13::pattern::Pattern;
+    use crate::day13::valley::Valley;
 
     #[test]
     fn test_find_vertical_mirror() {
@@ -1,13 +1,14 @@
 use std::rc::Rc;
 use std::str::FromStr;
-use crate::pattern::{Reflection, Pattern};
+use crate::grid::GridError;
+use crate::day13::pattern::{Reflection, Pattern};
 
 #[derive(Debug)]
-pub(crate) struct Valley {
-    pub(crate) patterns: Rc<[Pattern]>
+pub struct Valley {
+    pub patterns: Rc<[Pattern]>
 }
 impl Valley {
-    pub(crate) fn summarise_notes<'a, F, I>(&'a self, find: F) -> usize
+    pub fn summarise_notes<'a, F, I>(&'a self, find: F) -> usize
         where
             F: Fn(&'a [String]) -> I,
             I: Iterator<Item = Reflection> + 'a
@@ -28,13 +29,19 @@ impl Valley {
     }
 }
 impl FromStr for Valley {
-    type Err = ();
+    type Err = GridError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // report errors against the line numbers of the whole input, not of the pattern
+        let mut offset = 0;
         Ok(Valley {
             patterns: s.split("\n\n")
-                .map(|pat| pat.parse::<Pattern>().expect("Ops!"))
-                .collect::<Rc<[Pattern]>>()
+                .map(|pat| {
+                    let p = pat.parse::<Pattern>().map_err(|e| e.offset_lines(offset));
+                    offset += pat.lines().count() + 1;
+                    p
+                })
+                .collect::<Result<Rc<[Pattern]>,_>>()?
         })
     }
 }
@@ -44,6 +51,15 @@ impl FromStr for Valley {
 mod test {
     use super::*;
 
+    #[test]
+    fn test_parse_error() {
+        let input = "#.##\n..#.\n\n##..\n#..#\n.#x.";
+        assert_eq!(
+            input.parse::<Valley>().err(),
+            Some(GridError::InvalidCell { line: 6, column: 3, found: 'x' })
+        );
+    }
+
     #[test]
     fn test_calculate_smudged_input() {
         let input = std::fs::read_to_string("src/bin/day13/sample.txt").expect("Ops!");
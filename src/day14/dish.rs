@@ -1,16 +1,26 @@
 use std::fmt::{Debug, Formatter, Write};
 use std::rc::Rc;
 use std::str::FromStr;
+use crate::grid::{self, Coord, Grid, GridError};
 use Direction as D;
 
 #[derive(Copy, Clone, Debug)]
-pub(crate) enum Direction { North, West, South, East }
+pub enum Direction { North, West, South, East }
 
-#[derive(Default)]
-pub(crate) struct ReflectorDish {
-    width: usize,
-    lines: usize,
-    pub(crate) layout: Vec<u8>
+impl From<Direction> for grid::Direction {
+    fn from(dir: Direction) -> Self {
+        match dir {
+            D::North => grid::Direction::Up,
+            D::West => grid::Direction::Left,
+            D::South => grid::Direction::Down,
+            D::East => grid::Direction::Right,
+        }
+    }
+}
+
+#[derive(Default, Clone)]
+pub struct ReflectorDish {
+    pub layout: Grid<u8>
 }
 
 type Position = usize;
@@ -18,25 +28,19 @@ type Cost = usize;
 
 impl ReflectorDish {
     fn next(&self, idx: usize, dir:Direction) -> Option<Position> {
-        match dir {
-            D::East if idx % self.width < self.width - 1 => Some(idx + 1),
-            D::West if idx % self.width > 0 => Some(idx - 1),
-            D::North if idx > self.width - 1 => Some(idx - self.width),
-            D::South if idx < self.layout.len() - self.width => Some(idx + self.width),
-            _ => None
-        }
+        self.layout.step(idx, dir.into())
     }
     fn move_rock(&mut self, idx: usize, dir:Direction) -> Option<Cost> {
         if idx >= self.layout.len() { return None }
         if let Some(next) = self.next(idx,dir) {
             if self.layout[next] == b'.' {
-                self.layout.swap(idx, next);
+                self.layout.cells_mut().swap(idx, next);
                 return self.move_rock(next, dir)
             }
         }
-        Some(idx / self.lines)
+        Some(self.layout.coord_of(idx).y)
     }
-    pub(crate) fn tilt(&mut self, dir: Direction) -> Cost {
+    pub fn tilt(&mut self, dir: Direction) -> Cost {
         match dir {
             D::East => self.round_rocks_w2e().rev().collect::<Rc<[Position]>>(),
             D::West => self.round_rocks_w2e().collect::<Rc<[Position]>>(),
@@ -47,21 +51,21 @@ impl ReflectorDish {
             // .inspect(|s| print!("idx: {s} -> "))
             .map(|&index| self
                 .move_rock(index, dir)
-                .map(|cost| self.lines - cost)
+                .map(|cost| self.layout.height() - cost)
                 .unwrap()
             )
             // .inspect(|s| println!("{s}"))
             .sum::<Cost>()
     }
-    pub(crate) fn spin_cycle(&mut self) -> Cost {
+    pub fn spin_cycle(&mut self) -> Cost {
         [D::North,D::West,D::South,D::East]
             .into_iter()
             .map(|dir| self.tilt(dir))
             .last()
             .unwrap()
     }
-    pub(crate) fn spin_cycle_nth(&mut self, nth: usize) -> Option<Cost> {
-        let mut map = std::collections::HashMap::<Vec<u8>,usize>::new();
+    pub fn spin_cycle_nth(&mut self, nth: usize) -> Option<Cost> {
+        let mut map = std::collections::HashMap::<Grid<u8>,usize>::new();
 
         (1..nth)
             .map(|cycle| (
@@ -71,7 +75,7 @@ impl ReflectorDish {
             ))
             .skip_while(|(cycle, _, seen)|
                 seen.map(|last| {
-                    (nth - last) % (cycle - last) != 0
+                    !(nth - last).is_multiple_of(cycle - last)
                 }).unwrap_or(true)
             )
             .map(|(_,cost,_)| cost)
@@ -84,24 +88,20 @@ impl ReflectorDish {
             .map(|(idx,_)| idx )
     }
     fn round_rocks_w2e(&self) -> impl DoubleEndedIterator<Item=Position> + '_ {
-        (0..self.width)
+        (0..self.layout.width())
             .flat_map(move |x|{
-                (0..self.lines).map(move |y| y * self.lines + x )
+                (0..self.layout.height()).map(move |y| self.layout.index_of(Coord::new(x, y)))
             })
             .filter(|&idx| self.layout[idx] == b'O')
     }
 }
 
 impl FromStr for ReflectorDish {
-    type Err = ();
+    type Err = GridError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(ReflectorDish {
-            width: s.lines().next().map(|s| s.len()).unwrap(),
-            lines: s.lines().count(),
-            layout: s.lines()
-                .flat_map(|line| line.bytes())
-                .collect::<Vec<_>>()
+            layout: Grid::parse_with(s, |c| matches!(c, '.' | '#' | 'O').then_some(c as u8))?
         })
     }
 }
@@ -109,9 +109,9 @@ impl FromStr for ReflectorDish {
 impl Debug for ReflectorDish {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         f.write_str("ReflectorDish\n")?;
-        f.write_str(&format!("Width:{}, Length:{}", self.width, self.lines))?;
+        f.write_str(&format!("Width:{}, Length:{}", self.layout.width(), self.layout.height()))?;
         for (i,c) in self.layout.iter().enumerate() {
-            if i % self.width == 0 {
+            if i % self.layout.width() == 0 {
                 f.write_char('\n')?
             };
             f.write_char(' ')?;
@@ -0,0 +1,23 @@
+pub mod dish;
+
+use dish::{ReflectorDish, Direction};
+use crate::Solution;
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    type Input = ReflectorDish;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> ReflectorDish {
+        input.parse::<ReflectorDish>().unwrap_or_default()
+    }
+    fn part1(dish: &ReflectorDish) -> usize {
+        dish.clone().tilt(Direction::North)
+    }
+    fn part2(dish: &ReflectorDish) -> usize {
+        dish.clone().spin_cycle_nth(1000000000).unwrap()
+    }
+}
@@ -1,11 +1,11 @@
 use std::ops::Deref;
 use std::rc::Rc;
 
-pub(crate) trait HashLen {
+pub trait HashLen {
     fn hash_algo(&self) -> usize;
 }
 
-pub(crate) type Hash = usize;
+pub type Hash = usize;
 impl HashLen for &str {
     fn hash_algo(&self) -> Hash {
         self.bytes().fold(0usize, |acc, b| ((acc + b as usize) * 17) % 256 )
@@ -20,7 +20,7 @@ impl HashLen for Rc<str> {
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::operation::Label;
+    use crate::day15::operation::Label;
 
     static INPUT: &str = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
 
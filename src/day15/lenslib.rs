@@ -1,9 +1,9 @@
-use crate::operation::{Instruction, FocalLength, Label};
+use crate::day15::operation::{Instruction, FocalLength, Label};
 
 type Len = (Label,FocalLength);
 
 #[derive(Debug)]
-pub(crate) struct ParabolicReflector {
+pub struct ParabolicReflector {
     boxes: [Vec<Len>;256]
 }
 
@@ -18,7 +18,7 @@ impl Default for ParabolicReflector {
 }
 
 impl ParabolicReflector {
-    pub(crate) fn focusing_power(&self) -> usize {
+    pub fn focusing_power(&self) -> usize {
         self.boxes_iter()
             .map(|(idx,b0x)|{
                 b0x.iter()
@@ -28,7 +28,7 @@ impl ParabolicReflector {
             })
             .sum::<usize>()
     }
-    pub(crate) fn initiation(&mut self, op: &Instruction) -> bool {
+    pub fn initiation(&mut self, op: &Instruction) -> bool {
         self.boxes
             .get_mut( op.hash() )
             .map(|boxes| {
@@ -0,0 +1,36 @@
+pub mod hash;
+pub mod operation;
+pub mod lenslib;
+
+use hash::HashLen;
+use operation::Instruction;
+use lenslib::ParabolicReflector;
+use crate::Solution;
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+    type Input = String;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> String {
+        input.to_string()
+    }
+    fn part1(input: &String) -> usize {
+        input
+            .split([','])
+            .map(|label| label.hash_algo() )
+            .sum::<usize>()
+    }
+    fn part2(input: &String) -> usize {
+        let mut lb = ParabolicReflector::default();
+        input
+            .split([','])
+            .map(|op| op.parse::<Instruction>().expect("Cannot parse instruction"))
+            .for_each(|op| { lb.initiation(&op); });
+
+        lb.focusing_power()
+    }
+}
@@ -3,23 +3,23 @@ use std::fmt::{Debug, Display, Formatter};
 use std::rc::Rc;
 use std::str::FromStr;
 
-use crate::hash::{Hash, HashLen};
+use crate::day15::hash::{Hash, HashLen};
 
-pub(crate) type FocalLength = usize;
-pub(crate) type Label = Rc<str>;
+pub type FocalLength = usize;
+pub type Label = Rc<str>;
 
 #[derive(Debug,PartialEq)]
-pub(crate) enum Instruction {
+pub enum Instruction {
     Remove(Label),
     Store(Label,FocalLength)
 }
 use Instruction as I;
 
 impl Instruction {
-    pub(crate) fn hash(&self) -> Hash {
+    pub fn hash(&self) -> Hash {
         self.label().hash_algo()
     }
-    pub(crate) fn label(&self) -> &Label {
+    pub fn label(&self) -> &Label {
         match self {
             I::Remove(l) => l,
             I::Store(l, _) => l,
@@ -29,7 +29,7 @@ impl Instruction {
 }
 
 #[derive(PartialEq)]
-pub(crate) enum InstructionError {
+pub enum InstructionError {
     InvalidFocalLength,
     InvalidOperand
 }
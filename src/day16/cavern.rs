@@ -0,0 +1,233 @@
+use std::fmt::{Debug, Formatter};
+use std::iter::repeat;
+use std::str::FromStr;
+use crate::day16::direction::Direction;
+use crate::grid::{self, Grid, GridError};
+
+pub type Position = usize;
+pub type Energy = usize;
+
+pub fn entry_points(w:usize, h:usize) -> impl Iterator<Item=(Position, Direction)> + 'static {
+    use Direction as D;
+    let len = w*h;
+    (0..w).zip(repeat(D::Down))
+        .chain((len-w..len).zip(repeat(D::Up)))
+        .chain((0..h).map(move |l| l*w).zip(repeat(D::Right)))
+        .chain((1..=h).map(move |l| l*w-1).zip(repeat(D::Left)))
+}
+
+#[derive(Clone)]
+pub struct Cavern {
+    con: Grid<u8>,
+    nrg: Box<[(bool,Vec<Direction>)]>
+}
+
+impl Cavern {
+    pub fn width(&self) -> usize { self.con.width() }
+    pub fn height(&self) -> usize { self.con.height() }
+
+    pub fn measure_energy(&self) -> Energy {
+        self.nrg.iter().filter(|(c,_)| *c).count()
+    }
+    pub fn energise(&mut self, idx: Position, dir:Direction) {
+        self.nrg.iter_mut().for_each(|(n,v)|{ *n = false; v.clear(); });
+        self.move_beam(idx,dir)
+    }
+    pub fn tile(&self, idx: Position) -> u8 {
+        self.con[idx]
+    }
+    /// The tile next to `idx` towards `dir`, if not beyond the edge of the cavern
+    pub fn step(&self, idx: Position, dir:Direction) -> Option<Position> {
+        use Direction as D;
+        let dir = match dir {
+            D::Up => grid::Direction::Up,
+            D::Right => grid::Direction::Right,
+            D::Down => grid::Direction::Down,
+            D::Left => grid::Direction::Left,
+            D::UpDown | D::LeftRight => return None
+        };
+        self.con.step(idx, dir)
+    }
+
+    fn move_beam(&mut self, idx: Position, dir:Direction) {
+        use Direction as D;
+
+        // Beams still to follow, as the tile entered and the direction entered with;
+        // a worklist rather than recursion, so that a beam can cross any number of tiles
+        let mut beams = vec![(idx, dir)];
+
+        while let Some((idx, dir)) = beams.pop() {
+            // Has the light-beam fallen into a circle ?
+            if self.con[idx] != b'.' && self.has_entered_cycle(idx, dir) { continue }
+
+            // Energise cell
+            self.nrg[idx].0 = true;
+
+            // Find new direction based on current tile; beams split left or up are followed first
+            let dirs = match dir.next(self.con[idx]) {
+                D::LeftRight => [Some(D::Right), Some(D::Left)],
+                D::UpDown => [Some(D::Down), Some(D::Up)],
+                any => [Some(any), None],
+            };
+            beams.extend(dirs.into_iter().flatten().filter_map(|d| self.step(idx, d).map(|pos| (pos, d))));
+        }
+    }
+
+    fn has_entered_cycle(&mut self, idx: Position, dir: Direction) -> bool {
+        use Direction as D;
+
+        // Cycle Detection: have we enter the contraption from the same direction before ?
+        if self.nrg[idx].1.contains(&dir) { return true }
+
+        // Store light-beam direction at contraption point, for cycles detection
+        // Optimise around splitters by storing both opposite directions
+        // this stops us from re-entering the cycle from the opposite direction
+        match (self.con[idx],dir) {
+            (b'-', D::Up|D::Down) => self.nrg[idx].1.extend_from_slice(&[D::Up, D::Down,]),
+            (b'|', D::Left|D::Right) => self.nrg[idx].1.extend_from_slice(&[D::Left, D::Right]),
+            _ => self.nrg[idx].1.push(dir)
+        };
+        // println!("{:?} -> {:?}",(self.con[idx] as char,dir, idx), self.nrg[idx].1);
+        false
+    }
+}
+
+impl FromStr for Cavern {
+    type Err = GridError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let con = Grid::parse_with(s, |c| matches!(c, '.' | '|' | '-' | '/' | '\\').then_some(c as u8))?;
+        Ok(Cavern {
+            nrg: (0..con.len()).map(|_| (false,Vec::with_capacity(4))).collect::<Box<[_]>>(),
+            con,
+        })
+    }
+}
+
+impl Debug for Cavern {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f,"Cavern")?;
+        writeln!(f,"Width:{}, Length:{}", self.width(), self.height())?;
+        writeln!(f,"{a:w$}  {b:w$}", w=self.width()<<1, a = "Contraption", b = "Energy")?;
+        writeln!(f,"{a:-<w$}  {a:-<w$}", w=self.width()<<1, a="")?;
+
+        let mut citer = self.con.iter();
+        let mut eiter = self.nrg.iter();
+        for _ in 0..self.height() {
+            for _ in 0..self.width() { write!(f, "{:2}", *citer.next().unwrap() as char)? };
+            write!(f, "  ")?;
+            for _ in 0..self.width() { write!(f, "{:2}", if eiter.next().unwrap().0 {'#'} else {'.'} )? };
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+#[cfg(test)]
+pub mod test {
+    use super::*;
+    use Direction as D;
+
+    #[test]
+    fn test_find_max_energy() {
+        let inp = std::fs::read_to_string("src/bin/day16/sample.txt").expect("Ops!");
+        let mut cavern = inp.parse::<Cavern>().unwrap();
+
+        let m = entry_points(cavern.width(),cavern.height())
+            .inspect(|d| print!("{:?} -> ",d))
+            .map(|(idx,dir)| {
+                cavern.energise(idx,dir);
+                println!("{:?}",cavern);
+                cavern.measure_energy()
+            })
+            .inspect(|d| println!("{d:2}"))
+            .max();
+        assert_eq!(m,Some(51));
+    }
+    #[test]
+    fn test_move_bean() {
+        let inp = std::fs::read_to_string("src/bin/day16/sample.txt").expect("Ops!");
+        let mut cavern = inp.parse::<Cavern>().unwrap();
+
+        println!("{:?}",cavern.energise(0,D::Right));
+        println!("{:?}",cavern);
+        assert_eq!(cavern.measure_energy(), 46);
+    }
+
+    // A cavern of `w` by `h` tiles, mirrors leading a beam entering top left going right through every tile
+    // back and forth a line at a time, with splitters it passes through along its way
+    pub fn serpentine(w: usize, h: usize) -> String {
+        (0..h).map(|y| {
+            let mut line = vec![b'.'; w];
+            if y % 2 == 0 {
+                if y > 0 { line[0] = b'\\' }
+                line[w - 1] = b'\\';
+                line[w / 2] = b'-';
+            } else {
+                line[0] = b'/';
+                line[w - 1] = b'/';
+                line[w / 3] = b'-';
+            }
+            String::from_utf8(line).unwrap()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+    }
+
+    // A cavern of `w` by `h` tiles, mostly empty with mirrors and splitters picked pseudo-randomly from `seed`
+    pub fn scattered(w: usize, h: usize, mut seed: u64) -> String {
+        (0..h).map(|_| {
+            (0..w).map(|_| {
+                seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+                match (seed >> 33) % 12 { 0 => '/', 1 => '\\', 2 => '-', 3 => '|', _ => '.' }
+            })
+            .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+    }
+
+    #[test]
+    fn test_move_beam_large_cavern() {
+        // a beam through a million tiles, one recursive call per tile would overflow the stack
+        let mut cavern = serpentine(1000, 1000).parse::<Cavern>().unwrap();
+        cavern.energise(0, D::Right);
+        assert_eq!(cavern.measure_energy(), 1_000_000);
+
+        let mut cavern = serpentine(9, 4).parse::<Cavern>().unwrap();
+        cavern.energise(0, D::Right);
+        println!("{:?}", cavern);
+        assert_eq!(cavern.measure_energy(), 36);
+    }
+
+    #[test]
+    fn test_move_beam_matches_naive_trace() {
+        let mut cavern = scattered(40, 40, 0x2545_f491).parse::<Cavern>().unwrap();
+
+        // every tile reached by following each (tile, direction) state at most once
+        let naive = |cavern: &Cavern, idx: Position, dir: Direction| {
+            let mut seen = std::collections::HashSet::new();
+            let mut queue = vec![(idx, dir)];
+            while let Some((idx, dir)) = queue.pop() {
+                if !seen.insert((idx, dir as u8)) { continue }
+                let dirs = match dir.next(cavern.con[idx]) {
+                    D::LeftRight => vec![D::Left, D::Right],
+                    D::UpDown => vec![D::Up, D::Down],
+                    any => vec![any],
+                };
+                queue.extend(dirs.into_iter().filter_map(|d| cavern.step(idx, d).map(|p| (p, d))));
+            }
+            seen.into_iter().map(|(idx, _)| idx).collect::<std::collections::HashSet<_>>().len()
+        };
+        for (idx, dir) in entry_points(cavern.width(), cavern.height()) {
+            cavern.energise(idx, dir);
+            assert_eq!(cavern.measure_energy(), naive(&cavern, idx, dir), "{idx} {dir:?}");
+        }
+    }
+
+    #[test]
+    fn test_parse_cavern() {
+        let inp = std::fs::read_to_string("src/bin/day16/sample.txt").expect("Ops!");
+        let cavern = inp.parse::<Cavern>().unwrap();
+        println!("{:?}",cavern);
+    }
+}
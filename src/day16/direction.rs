@@ -1,9 +1,9 @@
 #[derive(Clone, Copy, Debug, PartialEq)]
-pub(crate) enum Direction { Up, Left, Down, Right, UpDown, LeftRight }
+pub enum Direction { Up, Left, Down, Right, UpDown, LeftRight }
 use Direction as D;
 
 impl Direction {
-    pub(crate) fn next(&self, tile: u8) -> Direction {
+    pub fn next(&self, tile: u8) -> Direction {
         match (tile, self) {
             (b'/', D::Right) => D::Up,
             (b'/', D::Left) => D::Down,
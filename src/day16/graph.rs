@@ -0,0 +1,250 @@
+use crate::day16::{cavern::{Cavern, Energy, Position}, direction::Direction};
+
+type NodeId = usize;
+
+/// A set of cavern tiles, or of components, a bit each
+struct Tiles(Vec<u64>);
+
+impl Tiles {
+    fn new(len: usize) -> Tiles {
+        Tiles(vec![0; len.div_ceil(64)])
+    }
+    fn insert(&mut self, idx: Position) {
+        self.0[idx / 64] |= 1 << (idx % 64);
+    }
+    fn contains(&self, idx: Position) -> bool {
+        self.0[idx / 64] & (1 << (idx % 64)) != 0
+    }
+    fn count(&self) -> usize {
+        self.0.iter().map(|w| w.count_ones() as usize).sum()
+    }
+}
+
+/// The tiles a beam crosses until it leaves the cavern, or until it splits at a splitter
+struct Segment {
+    tiles: Vec<Position>,
+    splits_at: Option<NodeId>,
+}
+
+/// The cavern's beams as segments running between splitters
+///
+/// A node stands for a splitter hit on its flat side, which sends out the same two beams whichever side it's hit from;
+/// nodes lead to the nodes their two beams split at next. Nodes that lead back to each other energise the same tiles,
+/// so every strongly connected set of nodes is collapsed into a component, keeping only the tiles its own beams cross
+/// and the components they lead to; an entry point takes the union over the components its beam goes on to reach
+pub struct BeamGraph<'a> {
+    cavern: &'a Cavern,
+    // node of each tile holding a splitter
+    node_at: Box<[Option<NodeId>]>,
+    // component of each node
+    component: Vec<usize>,
+    // tiles crossed by the beams split within each component
+    tiles: Vec<Vec<Position>>,
+    // other components each component leads to
+    reaches: Vec<Vec<usize>>,
+}
+
+impl BeamGraph<'_> {
+    pub fn new(cavern: &Cavern) -> BeamGraph<'_> {
+        let len = cavern.width() * cavern.height();
+        let mut splitters = vec![];
+        let node_at = (0..len)
+            .map(|idx| matches!(cavern.tile(idx), b'-' | b'|').then(|| { splitters.push(idx); splitters.len() - 1 }))
+            .collect::<Box<[_]>>();
+
+        // the splitter's own tile and the tiles crossed by the two beams it sends out
+        let mut node_tiles = vec![];
+        let mut next = vec![];
+        for &idx in &splitters {
+            let dirs = match cavern.tile(idx) {
+                b'-' => [Direction::Left, Direction::Right],
+                _ => [Direction::Up, Direction::Down],
+            };
+            let segments = dirs.map(|d| cavern.step(idx, d).map(|pos| trace(cavern, &node_at, pos, d, Some((pos, d)))));
+            let segments = segments.into_iter().flatten().collect::<Vec<_>>();
+            node_tiles.push(std::iter::once(idx).chain(segments.iter().flat_map(|s| s.tiles.iter().copied())).collect::<Vec<_>>());
+            next.push(segments.iter().filter_map(|s| s.splits_at).collect::<Vec<_>>());
+        }
+
+        let components = strongly_connected(&next);
+        let mut component = vec![0; splitters.len()];
+        for (c, nodes) in components.iter().enumerate() {
+            nodes.iter().for_each(|&n| component[n] = c);
+        }
+        let (mut tiles, mut reaches) = (Vec::with_capacity(components.len()), Vec::with_capacity(components.len()));
+        for (c, nodes) in components.iter().enumerate() {
+            let mut own = nodes.iter().flat_map(|&n| std::mem::take(&mut node_tiles[n])).collect::<Vec<_>>();
+            own.sort_unstable();
+            own.dedup();
+            let mut to = nodes.iter()
+                .flat_map(|&n| next[n].iter().map(|&m| component[m]))
+                .filter(|&d| d != c)
+                .collect::<Vec<_>>();
+            to.sort_unstable();
+            to.dedup();
+            tiles.push(own);
+            reaches.push(to);
+        }
+        BeamGraph { cavern, node_at, component, tiles, reaches }
+    }
+    /// Same as [`Cavern::energise`] followed by [`Cavern::measure_energy`], without tracing the beam again
+    pub fn energy(&self, idx: Position, dir: Direction) -> Energy {
+        let segment = trace(self.cavern, &self.node_at, idx, dir, None);
+        let mut set = Tiles::new(self.node_at.len());
+        segment.tiles.iter().for_each(|&idx| set.insert(idx));
+
+        if let Some(node) = segment.splits_at {
+            let mut seen = Tiles::new(self.tiles.len());
+            let mut stack = vec![self.component[node]];
+            seen.insert(self.component[node]);
+            while let Some(c) = stack.pop() {
+                self.tiles[c].iter().for_each(|&idx| set.insert(idx));
+                for &d in &self.reaches[c] {
+                    if !seen.contains(d) {
+                        seen.insert(d);
+                        stack.push(d);
+                    }
+                }
+            }
+        }
+        set.count()
+    }
+    pub fn components(&self) -> usize {
+        self.tiles.len()
+    }
+}
+
+/// Follows a beam entering tile `idx` going `dir` through mirrors, empty tiles and the pointed end of splitters
+///
+/// Without splitting, a beam can only go round in a loop by coming back to where it started, given as `start`
+fn trace(cavern: &Cavern, node_at: &[Option<NodeId>], mut idx: Position, mut dir: Direction, start: Option<(Position, Direction)>) -> Segment {
+    use Direction as D;
+
+    let mut tiles = vec![];
+    loop {
+        match dir.next(cavern.tile(idx)) {
+            D::LeftRight | D::UpDown => return Segment { tiles, splits_at: node_at[idx] },
+            next => {
+                tiles.push(idx);
+                dir = next;
+            }
+        }
+        match cavern.step(idx, dir) {
+            Some(pos) if Some((pos, dir)) == start => return Segment { tiles, splits_at: None },
+            Some(pos) => idx = pos,
+            None => return Segment { tiles, splits_at: None },
+        }
+    }
+}
+
+/// Tarjan's algorithm, without recursion, returning components in reverse topological order
+fn strongly_connected(next: &[Vec<NodeId>]) -> Vec<Vec<NodeId>> {
+    // order each node was first visited in, and the lowest order reachable from it
+    let mut order: Vec<Option<usize>> = vec![None; next.len()];
+    let mut low = vec![0; next.len()];
+    let mut on_stack = vec![false; next.len()];
+    let (mut stack, mut components, mut visited) = (vec![], vec![], 0);
+
+    for root in 0..next.len() {
+        if order[root].is_some() { continue }
+        // nodes being visited, with the index of the next edge to follow
+        let mut path = vec![(root, 0)];
+        while let Some(&mut (node, ref mut edge)) = path.last_mut() {
+            if *edge == 0 && order[node].is_none() {
+                order[node] = Some(visited);
+                low[node] = visited;
+                visited += 1;
+                stack.push(node);
+                on_stack[node] = true;
+            }
+            if let Some(&m) = next[node].get(*edge) {
+                *edge += 1;
+                match order[m] {
+                    None => path.push((m, 0)),
+                    Some(o) if on_stack[m] => low[node] = low[node].min(o),
+                    _ => (),
+                }
+                continue
+            }
+            path.pop();
+            if let Some(&(parent, _)) = path.last() {
+                low[parent] = low[parent].min(low[node]);
+            }
+            if Some(low[node]) == order[node] {
+                let at = stack.iter().rposition(|&n| n == node).expect("node is on the stack");
+                let component = stack.split_off(at);
+                component.iter().for_each(|&n| on_stack[n] = false);
+                components.push(component);
+            }
+        }
+    }
+    components
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::day16::cavern::{entry_points, test::{scattered, serpentine}};
+
+    fn assert_matches_cavern(cavern: Cavern) {
+        let graph = BeamGraph::new(&cavern);
+        let mut traced = cavern.clone();
+        for (idx, dir) in entry_points(cavern.width(), cavern.height()) {
+            traced.energise(idx, dir);
+            assert_eq!(graph.energy(idx, dir), traced.measure_energy(), "{idx} {dir:?}");
+        }
+    }
+
+    #[test]
+    fn test_beam_graph() {
+        let inp = std::fs::read_to_string("src/bin/day16/sample.txt").expect("Ops!");
+        let cavern = inp.parse::<Cavern>().unwrap();
+        let graph = BeamGraph::new(&cavern);
+        assert_eq!(graph.energy(0, Direction::Right), 46);
+        assert_matches_cavern(cavern);
+
+        for seed in [0x2545_f491, 7, 1234] {
+            let cavern = scattered(40, 40, seed).parse::<Cavern>().unwrap();
+            let graph = BeamGraph::new(&cavern);
+            // beams split back and forth between some splitters, fewer components than splitters
+            assert!(graph.components() < graph.node_at.iter().flatten().count());
+            assert_matches_cavern(cavern);
+        }
+        assert_matches_cavern(serpentine(30, 20).parse::<Cavern>().unwrap());
+    }
+
+    #[test]
+    fn test_beam_graph_large_cavern() {
+        // some 166k splitters, a bitset of the million tiles for each component would take gigabytes
+        let cavern = scattered(1000, 1000, 0x2545_f491).parse::<Cavern>().unwrap();
+        let graph = BeamGraph::new(&cavern);
+        let splitters = graph.node_at.iter().flatten().count();
+        assert!(splitters > 150_000);
+        // beams cross each tile at most once per heading
+        assert!(graph.tiles.iter().map(Vec::len).sum::<usize>() <= 4 * 1_000_000 + splitters);
+
+        let mut traced = cavern.clone();
+        for (idx, dir) in entry_points(1000, 1000).step_by(397) {
+            traced.energise(idx, dir);
+            assert_eq!(graph.energy(idx, dir), traced.measure_energy(), "{idx} {dir:?}");
+        }
+    }
+
+    #[test]
+    fn test_trace_loop() {
+        // both beams of the splitter come back round through its pointed ends, looping forever
+        let cavern = "/..\\\n\\-./".parse::<Cavern>().unwrap();
+        let graph = BeamGraph::new(&cavern);
+        assert_eq!(graph.energy(1, Direction::Down), 8);
+        assert_matches_cavern(cavern);
+    }
+
+    #[test]
+    fn test_strongly_connected() {
+        // 0 -> 1 -> 2 -> 0, 2 -> 3, 3 -> 3, 4 -> 1
+        let next = vec![vec![1], vec![2], vec![0, 3], vec![3], vec![1]];
+        let mut components = strongly_connected(&next);
+        components.iter_mut().for_each(|c| c.sort());
+        assert_eq!(components, [vec![3], vec![0, 1, 2], vec![4]]);
+    }
+}
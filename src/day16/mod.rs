@@ -0,0 +1,37 @@
+pub mod direction;
+pub mod cavern;
+pub mod graph;
+
+use cavern::{Cavern,entry_points};
+use direction::Direction as D;
+use graph::BeamGraph;
+use rayon::prelude::*;
+use crate::Solution;
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+    type Input = Cavern;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Cavern {
+        input.parse::<Cavern>().unwrap()
+    }
+    fn part1(cavern: &Cavern) -> usize {
+        let mut cavern = cavern.clone();
+        cavern.energise(0, D::Right);
+        cavern.measure_energy()
+    }
+    fn part2(cavern: &Cavern) -> usize {
+        // beams between splitters are traced once, leaving only the beam from each entry point to its first splitter
+        let graph = BeamGraph::new(cavern);
+        entry_points(cavern.width(), cavern.height())
+            .collect::<Vec<_>>()
+            .into_par_iter()
+            .map(|(idx,dir)| graph.energy(idx,dir))
+            .max()
+            .unwrap()
+    }
+}
@@ -1,15 +1,15 @@
 use std::cmp::Ordering;
-use crate::direction::Direction;
+use crate::day17::direction::Direction;
 
-pub(crate) type Heat = u16;
-pub(crate) type Position = usize;
-pub(crate) type Step = usize;
+pub type Heat = u16;
+pub type Position = usize;
+pub type Step = usize;
 
 #[derive(Debug, Eq, PartialEq, Hash, PartialOrd, Copy, Clone)]
-pub(crate) struct CityBlock(pub Position, pub Direction, pub Step);
+pub struct CityBlock(pub Position, pub Direction, pub Step);
 
 #[derive(Debug, Eq)]
-pub(crate) struct QueuedCityBlock(pub Heat, pub CityBlock);
+pub struct QueuedCityBlock(pub Heat, pub CityBlock);
 
 impl PartialEq<Self> for QueuedCityBlock {
     fn eq(&self, other: &Self) -> bool {
@@ -1,64 +1,50 @@
 use std::fmt::{Debug, Formatter};
 use std::ops::Index;
-use std::rc::Rc;
 use std::str::FromStr;
-use crate::{
+use crate::grid::{Coord, Grid, GridError};
+use crate::day17::{
     crucible::Crucible,
     direction::Direction,
-    block::{Heat,Position,Step},
-    path::CityMapPath
+    block::{Heat,Position},
 };
-use Direction as D;
 
-pub(crate) struct CityMap {
-    width: usize,
-    lines: usize,
-    map: Rc<[Heat]>,
+pub struct CityMap {
+    map: Grid<Heat>,
 }
 
 impl CityMap {
     #[inline]
-    pub(crate) fn len(&self) -> usize { self.map.len() }
+    pub fn len(&self) -> usize { self.map.len() }
+    #[inline]
+    pub fn is_empty(&self) -> bool { self.map.is_empty() }
 
-    pub(crate) fn get_crucible(&self, pos: Position, dir: Direction) -> Crucible {
+    pub fn get_crucible(&self, pos: Position, dir: Direction) -> Crucible<'_> {
         Crucible::new(self,pos,dir)
     }
-    pub(crate) fn move_from(&self, from: Position, dir: Direction) -> Option<Position> {
-        if from >= self.map.len() { return None }
-        match dir {
-            D::Right if from % self.width < self.width-1 => Some(from + 1),
-            D::Left if from % self.width > 0 => Some(from - 1),
-            D::Up if from > self.width - 1 => Some(from - self.width),
-            D::Down if from < self.map.len() - self.width => Some(from + self.width),
-            _ => None
-        }
+    #[inline]
+    pub fn move_from(&self, from: Position, dir: Direction) -> Option<Position> {
+        let (dx, dy) = dir.offset();
+        self.map.coord_of(from)
+            .offset(dx, dy)
+            .filter(|&c| self.map.contains(c))
+            .map(|c| self.map.index_of(c))
     }
-    pub fn _display_path(&self, cm_path: &CityMapPath) {
-
-        let mut path: Vec<Option<(Heat, Direction, Step)>> = vec![None; self.len()];
-
-        cm_path.iter()
-            .for_each(|(heat, block)|
-                     path[block.0] = Some((heat, block.1, block.2))
-            );
-
-        for idx in 0..self.len() {
-            if idx % self.width == 0 { println!(); }
-            print!("{a}{:2}/{:<3?}:{b:2} |",
-                   self[idx],
-                   path[idx].map(|(h,..)| h).unwrap_or(0),
-                   a=if path[idx].is_some() {
-                       match path[idx].map(|(_,d,_)| d) {
-                           None => '◼', Some(D::Up) => '▲', Some(D::Down) => '▼',
-                           Some(D::Left) => '◀', Some(D::Right) => '▶',
-                       }
-                   } else { ' ' },
-                   b=path[idx].map(|(..,s)| s).unwrap_or(0)
-            );
-        }
-        println!();
+    /// Least heat lost entering any block
+    pub fn min_heat(&self) -> Heat {
+        self.map.iter().copied().min().unwrap_or(0)
     }
-
+    /// Fewest moves between two blocks; Manhattan distance, or Chebyshev distance when moving diagonally
+    pub fn distance(&self, a: Position, b: Position, diagonal: bool) -> usize {
+        let (a, b) = (self.map.coord_of(a), self.map.coord_of(b));
+        let (dx, dy) = (a.x.abs_diff(b.x), a.y.abs_diff(b.y));
+        if diagonal { dx.max(dy) } else { dx + dy }
+    }
+    #[inline]
+    pub fn width(&self) -> usize { self.map.width() }
+    #[inline]
+    pub fn height(&self) -> usize { self.map.height() }
+    #[inline]
+    pub fn coord_of(&self, pos: Position) -> Coord { self.map.coord_of(pos) }
 }
 impl Index<Position> for CityMap {
     type Output = Heat;
@@ -69,26 +55,20 @@ impl Index<Position> for CityMap {
 }
 
 impl FromStr for CityMap {
-    type Err = ();
+    type Err = GridError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(CityMap {
-            width: s.lines().next().unwrap().len(),
-            lines: s.lines().count(),
-            map: s
-                .lines()
-                .flat_map(|line| line.bytes())
-                .map(|c| (c - b'0') as Heat)
-                .collect::<Rc<_>>(),
+            map: Grid::parse_with(s, |c| c.to_digit(10).map(|d| d as Heat))?
         })
     }
 }
 impl Debug for CityMap {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         writeln!(f,"CityMap")?;
-        write!(f,"Width:{}, Lines:{}",self.width,self.lines)?;
+        write!(f,"Width:{}, Lines:{}",self.map.width(),self.map.height())?;
         for idx in 0..self.map.len() {
-            if idx % self.width == 0 { writeln!(f)?; }
+            if idx % self.map.width() == 0 { writeln!(f)?; }
             write!(f, "{:3}", self.map[idx])?;
         }
         Ok(())
@@ -98,6 +78,7 @@ impl Debug for CityMap {
 #[cfg(test)]
 mod test {
     use super::*;
+    use Direction as D;
 
     #[test]
     fn test_step_onto() {
@@ -119,20 +100,37 @@ mod test {
             ((168, D::Left), Some(167)),
             ((168, D::Up), Some(155)),
             ((168, D::Right), None),
-            ((168, D::Down), None)
+            ((168, D::Down), None),
+            ((0, D::DownRight), Some(14)),
+            ((14, D::UpLeft), Some(0)),
+            ((12, D::DownLeft), Some(24)),
+            ((12, D::UpRight), None)
         ];
         for ((p,d),out) in data {
             assert_eq!(map.move_from(p, d), out);
         }
     }
     #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            "2413\n32a5\n3255".parse::<CityMap>().err(),
+            Some(GridError::InvalidCell { line: 2, column: 3, found: 'a' })
+        );
+        // blocks losing no heat are fine
+        assert_eq!("2413\n3205".parse::<CityMap>().map(|m| m[6]), Ok(0));
+        assert_eq!(
+            "2413\n325\n3255".parse::<CityMap>().err(),
+            Some(GridError::RaggedLine { line: 2, expected: 4, found: 3, text: "325".into() })
+        );
+    }
+    #[test]
     fn test_parse_map() {
         let input = std::fs::read_to_string("src/bin/day17/sample.txt").expect("File Not Found!");
         let map = input.parse::<CityMap>().expect("ops");
 
         println!("{}",map.map.len());
         assert_eq!(
-            map.map,
+            map.map.cells(),
             [
                 2, 4, 1, 3, 4, 3, 2, 3, 1, 1, 3, 2, 3,
                 3, 2, 1, 5, 4, 5, 3, 5, 3, 5, 6, 2, 3,
@@ -147,7 +145,7 @@ mod test {
                 1, 2, 2, 4, 6, 8, 6, 8, 6, 5, 5, 6, 3,
                 2, 5, 4, 6, 5, 4, 8, 8, 8, 7, 7, 3, 5,
                 4, 3, 2, 2, 6, 7, 4, 6, 5, 5, 5, 3, 3
-            ].into()
+            ]
         )
     }
 }
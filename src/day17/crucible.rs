@@ -0,0 +1,420 @@
+
+use std::{cmp::Reverse, collections::{BinaryHeap, HashMap}};
+use crate::day17::{
+    citymap::CityMap,
+    direction::Direction,
+    block::*,
+    path::CityMapPath,
+    queue::PriorityQueue,
+    rules::MovementRules
+};
+
+/// How the search picks the next block to expand
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Strategy {
+    /// least heat lost so far
+    #[default]
+    Dijkstra,
+    /// least heat lost so far plus the least heat that could be lost reaching the target
+    AStar,
+}
+
+/// Work done by a search
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SearchStats {
+    /// blocks pulled from the queue and expanded into their neighbours
+    pub expanded: usize,
+    /// blocks pushed to the queue
+    pub queued: usize,
+}
+
+#[derive(Debug)]
+pub struct Crucible<'a> {
+    cmap: &'a CityMap,
+    pos: Position,
+    dir: Direction
+}
+
+impl<'a> Crucible<'a> {
+    pub fn new(map: &CityMap, pos: Position, dir: Direction) -> Crucible<'_> {
+        Crucible { cmap: map, pos, dir }
+    }
+    fn neighbour_blocks(&'a self, node: CityBlock, rules: &'a MovementRules) -> impl Iterator<Item=CityBlock> + 'a {
+        let CityBlock(pos, dir, step) = node;
+        rules.moves(dir, step)
+            // extract CityBlocks from valid moves remaining
+            .filter_map(move |d|
+                self.cmap.move_from(pos, d)
+                    .map(|p|
+                        CityBlock(p, d, if d == dir {step + 1} else { 1 })
+                    )
+            )
+    }
+
+    /// Path of least heat loss to `target`, moving as the rules allow, e.g. `0..3` straight blocks for the part 1 crucible
+    pub fn find_path_to(&mut self, target: Position, rules: impl Into<MovementRules>) -> Option<CityMapPath> {
+        self.search::<BinaryHeap<QueuedCityBlock>>(target, &rules.into(), Strategy::Dijkstra).0
+    }
+
+    /// Same as [`Crucible::find_path_to`] with a choice of search strategy and priority queue, reporting the work done
+    pub fn search<Q: PriorityQueue>(&mut self, target: Position, rules: &MovementRules, strategy: Strategy) -> (Option<CityMapPath>, SearchStats) {
+        // never overestimates the heat left to lose, as every move loses at least the least heat of any block
+        let min_heat = self.cmap.min_heat();
+        let estimate = |pos: Position| -> Heat {
+            match strategy {
+                Strategy::Dijkstra => 0,
+                Strategy::AStar => (self.cmap.distance(pos, target, rules.diagonal) as Heat).saturating_mul(min_heat),
+            }
+        };
+
+        let mut cost_map = HashMap::<CityBlock,(Heat, Option<CityBlock>)>::new();
+        let mut queue = Q::default();
+        let mut stats = SearchStats::default();
+
+        let start = CityBlock(self.pos, self.dir, 0);
+        queue.push(estimate(self.pos), start);                                                     // push starting conditions of zero heat, zero steps
+        cost_map.insert(start, (0, None));
+
+        while let Some((priority, block)) = queue.pop() {                                          // pull the next block with the least (estimated) heat cost from the queue
+            let heat = cost_map[&block].0;
+            if priority > heat + estimate(block.0) { continue }                                     // skip stale entries superseded by a cheaper route to the block
+            if block.0 == target {                                                                  // is this block our target ?
+                return (Some(CityMapPath::new(cost_map, block)), stats)                             // yes, return the path cost map with the starting block for traversing it
+            }
+            stats.expanded += 1;
+            for neighbour in self.neighbour_blocks(block, rules) {                                  // get all feasible neighbouring blocks given the constraints
+                let heat_sum = heat + self.cmap[neighbour.0]                                        // calculate cost if we are to move to this neighbour
+                    + rules.turn_heat(block.1, neighbour.1, block.2);
+                if heat_sum < cost_map.get(&neighbour).unwrap_or(&(Heat::MAX, None)).0 {           // is the cost higher than previously found ? if not, store it
+                    cost_map.insert(neighbour, (heat_sum, Some(block)));                            // remember the heat cost at this block along the block we stepped from
+                    queue.push(heat_sum + estimate(neighbour.0), neighbour);                        // push neighbouring block to priority queue for processing
+                    stats.queued += 1;
+                }
+            }
+        }
+        (None, stats)
+    }
+
+    /// The `k` cheapest distinct paths to `target`, cheapest first
+    ///
+    /// Every block can be settled up to `k` times rather than once, each time by a different path reaching it,
+    /// so the paths are kept as a tree of labels, each pointing at the label it was extended from.
+    ///
+    /// The paths are walks rather than simple paths: one may run through the same city block more than once, on
+    /// another heading or after another number of straight blocks, when that's among the `k` cheapest ways to the
+    /// target. Rules letting the crucible turn back, `MovementRules::reverse(true)`, bring such walks up among the
+    /// very cheapest; otherwise they come far down the list. A walk never comes back to the same block, heading and
+    /// straight blocks without losing more heat, which blocks losing no heat at all would otherwise allow forever
+    pub fn k_cheapest_paths(&mut self, target: Position, k: usize, rules: impl Into<MovementRules>) -> Vec<CityMapPath> {
+        let rules = rules.into();
+        // (heat, block, label extended from)
+        let mut labels = vec![(0, CityBlock(self.pos, self.dir, 0), None)];
+        let mut settled = HashMap::<CityBlock, usize>::new();
+        // heat of the `k` cheapest labels so far for each block; a label costing more than all of them is never settled
+        let mut cheapest = HashMap::<CityBlock, BinaryHeap<Heat>>::new();
+        let mut queue = BinaryHeap::from([Reverse((0, 0))]);
+        let mut paths = vec![];
+
+        while let Some(Reverse((heat, label))) = queue.pop() {
+            let block = labels[label].1;
+            let count = settled.entry(block).or_default();
+            if *count == k { continue }
+            *count += 1;
+
+            if block.0 == target {
+                let mut path = std::iter::successors(Some(label), |&l| labels[l].2)
+                    .map(|l| (labels[l].0, labels[l].1))
+                    .collect::<Vec<_>>();
+                path.reverse();
+                paths.push(CityMapPath::from_blocks(path));
+                if paths.len() == k { break }
+                continue
+            }
+            for neighbour in self.neighbour_blocks(block, &rules) {
+                if settled.get(&neighbour).is_some_and(|&c| c == k) { continue }
+                let heat_sum = heat + self.cmap[neighbour.0] + rules.turn_heat(block.1, neighbour.1, block.2);
+                let loops_back = std::iter::successors(Some(label), |&l| labels[l].2)
+                    .take_while(|&l| labels[l].0 == heat_sum)
+                    .any(|l| labels[l].1 == neighbour);
+                if loops_back { continue }
+                let kept = cheapest.entry(neighbour).or_default();
+                if kept.len() == k {
+                    if kept.peek().is_some_and(|&h| heat_sum >= h) { continue }
+                    kept.pop();
+                }
+                kept.push(heat_sum);
+                labels.push((heat_sum, neighbour, Some(label)));
+                queue.push(Reverse((heat_sum, labels.len() - 1)));
+            }
+        }
+        paths
+    }
+
+    /// Every path to `target` losing the least heat, walked back lazily as there can be exponentially many of them
+    ///
+    /// Blocks losing no heat let blocks tied for the least heat lead back to each other; walking back, a chain never
+    /// takes in the same block, heading and straight blocks twice, so it can't go round such a loop forever
+    pub fn optimal_paths(&mut self, target: Position, rules: impl Into<MovementRules>) -> OptimalPaths {
+        let rules = rules.into();
+        // least heat found for each block, along every block it can be reached from with that heat
+        let mut cost_map = HashMap::<CityBlock, (Heat, Vec<CityBlock>)>::new();
+        let mut queue = BinaryHeap::<QueuedCityBlock>::new();
+        let mut targets = vec![];
+
+        let start = CityBlock(self.pos, self.dir, 0);
+        cost_map.insert(start, (0, vec![]));
+        queue.push(QueuedCityBlock(0, start));
+
+        while let Some(QueuedCityBlock(heat, block)) = queue.pop() {
+            if heat > cost_map[&block].0 { continue }
+            // keep going until every block tied for the least heat at the target has been found
+            if targets.first().is_some_and(|&(best, _)| heat > best) { break }
+            if block.0 == target {
+                if !targets.contains(&(heat, block)) { targets.push((heat, block)) }
+                continue
+            }
+            // coming back to the start, where no heat has been lost yet, is never the cheapest way to it
+            for neighbour in self.neighbour_blocks(block, &rules).filter(|&n| n != start) {
+                let heat_sum = heat + self.cmap[neighbour.0] + rules.turn_heat(block.1, neighbour.1, block.2);
+                let entry = cost_map.entry(neighbour).or_insert((Heat::MAX, vec![]));
+                if heat_sum < entry.0 {
+                    *entry = (heat_sum, vec![block]);
+                    queue.push(QueuedCityBlock(heat_sum, neighbour));
+                } else if heat_sum == entry.0 && !entry.1.contains(&block) {
+                    entry.1.push(block);
+                }
+            }
+        }
+
+        OptimalPaths::new(cost_map, targets.into_iter().map(|(_, block)| block).collect(), start)
+    }
+}
+
+/// Paths of least heat loss, found by walking back every chain of tied blocks from each target block to the start
+#[derive(Debug)]
+pub struct OptimalPaths {
+    cost_map: HashMap<CityBlock, (Heat, Vec<CityBlock>)>,
+    targets: Vec<CityBlock>,
+    start: CityBlock,
+    // partial chains from a target block back towards the start
+    stack: Vec<Vec<CityBlock>>,
+}
+
+impl OptimalPaths {
+    fn new(cost_map: HashMap<CityBlock, (Heat, Vec<CityBlock>)>, targets: Vec<CityBlock>, start: CityBlock) -> OptimalPaths {
+        let stack = targets.iter().map(|&block| vec![block]).collect();
+        OptimalPaths { cost_map, targets, start, stack }
+    }
+    /// Number of optimal paths, counted without walking them unless blocks losing no heat lead round in a loop,
+    /// saturating at `u128::MAX`
+    pub fn total(&self) -> u128 {
+        type CostMap = HashMap<CityBlock, (Heat, Vec<CityBlock>)>;
+        // `None` while the paths to a block are being counted, hence `None` again on coming back round a loop
+        fn count(block: CityBlock, start: CityBlock, cost_map: &CostMap, memo: &mut HashMap<CityBlock, Option<u128>>) -> Option<u128> {
+            if block == start { return Some(1) }
+            match memo.get(&block) {
+                Some(&n) => return n,
+                None => memo.insert(block, None),
+            };
+            let mut n = 0u128;
+            for &parent in &cost_map[&block].1 {
+                n = n.saturating_add(count(parent, start, cost_map, memo)?);
+            }
+            memo.insert(block, Some(n));
+            Some(n)
+        }
+        let mut memo = HashMap::new();
+        self.targets.iter()
+            .try_fold(0u128, |sum, &t| Some(sum.saturating_add(count(t, self.start, &self.cost_map, &mut memo)?)))
+            // chains taking a loop in one direction or the other can only be told apart by walking them
+            .unwrap_or_else(|| OptimalPaths::new(self.cost_map.clone(), self.targets.clone(), self.start).count() as u128)
+    }
+}
+
+impl Iterator for OptimalPaths {
+    type Item = CityMapPath;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(chain) = self.stack.pop() {
+            let last = *chain.last().unwrap();
+            if last == self.start {
+                return Some(CityMapPath::from_blocks(
+                    chain.into_iter().rev().map(|b| (self.cost_map[&b].0, b)).collect()
+                ))
+            }
+            // a parent already on the chain leads back round a loop losing no heat
+            for &parent in self.cost_map[&last].1.iter().rev().filter(|p| !chain.contains(p)) {
+                let mut next = chain.clone();
+                next.push(parent);
+                self.stack.push(next);
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use Direction as D;
+
+    #[test]
+    fn test_find_path_to() {
+        let input = std::fs::read_to_string("src/bin/day17/sample.txt").expect("File Not Found!");
+        let map = input.parse::<CityMap>().expect("ops");
+
+        let test_ranges = |rng:std::ops::Range<Step>| -> Option<Heat> {
+            map.get_crucible(0, D::Right)
+                .find_path_to(map.len()-1, rng)
+                .map(|path| path.total_heat_loss())
+        };
+        assert_eq!(test_ranges(0..3), Some(102));
+        assert_eq!(test_ranges(4..10), Some(94));
+    }
+    #[test]
+    fn test_find_path_with_rules() {
+        let input = std::fs::read_to_string("src/bin/day17/sample.txt").expect("File Not Found!");
+        let map = input.parse::<CityMap>().expect("ops");
+        let heat_loss = |rules: MovementRules| {
+            map.get_crucible(0, D::Right)
+                .find_path_to(map.len()-1, rules)
+                .map(|path| path.total_heat_loss())
+        };
+
+        let part1 = heat_loss(MovementRules::default()).unwrap();
+        assert_eq!(part1, 102);
+        // turning can only cost more, while more freedom of movement can only cost less
+        assert!(heat_loss(MovementRules::default().turn_cost(1)).unwrap() > part1);
+        assert!(heat_loss(MovementRules::default().reverse(true)).unwrap() <= part1);
+        assert!(heat_loss(MovementRules::default().diagonal(true)).unwrap() < part1);
+        let monotone = map.get_crucible(0, D::Right)
+            .find_path_to(map.len()-1, MovementRules::from(0..12).limit(D::Up, 0).limit(D::Left, 0))
+            .unwrap();
+        assert!(monotone.iter().all(|(_, block)| matches!(block.1, D::Right | D::Down)));
+
+        // moving diagonally along the main diagonal of the 13x13 sample costs the heat of 12 blocks
+        let diagonal = (1..13).map(|i| map[i * 14]).sum::<Heat>();
+        assert!(heat_loss(MovementRules::from(0..12).diagonal(true)).unwrap() <= diagonal);
+
+        // with right and down forbidden there's no way out of the top left corner
+        assert_eq!(heat_loss(MovementRules::from(0..3).limit(D::Right, 0).limit(D::Down, 0)), None);
+    }
+    #[test]
+    fn test_search_strategies() {
+        use crate::day17::queue::BucketQueue;
+
+        for file in ["src/bin/day17/sample.txt", "src/bin/day17/sample2.txt"] {
+            let input = std::fs::read_to_string(file).expect("File Not Found!");
+            let map = input.parse::<CityMap>().expect("ops");
+
+            for rules in [MovementRules::from(0..3), MovementRules::from(4..10), MovementRules::from(0..3).diagonal(true).turn_cost(2)] {
+                let search = |strategy, bucket: bool| {
+                    let mut crucible = map.get_crucible(0, D::Right);
+                    let (path, stats) = if bucket {
+                        crucible.search::<BucketQueue>(map.len()-1, &rules, strategy)
+                    } else {
+                        crucible.search::<BinaryHeap<QueuedCityBlock>>(map.len()-1, &rules, strategy)
+                    };
+                    (path.map(|p| p.total_heat_loss()), stats)
+                };
+
+                let (heat, dijkstra) = search(Strategy::Dijkstra, false);
+                assert_eq!(heat, map.get_crucible(0, D::Right).find_path_to(map.len()-1, rules.clone()).map(|p| p.total_heat_loss()));
+                for (strategy, bucket) in [(Strategy::Dijkstra, true), (Strategy::AStar, false), (Strategy::AStar, true)] {
+                    let (h, stats) = search(strategy, bucket);
+                    assert_eq!(h, heat, "{file} {rules:?} {strategy:?} bucket:{bucket}");
+                    if strategy == Strategy::AStar { assert!(stats.expanded <= dijkstra.expanded) }
+                }
+            }
+        }
+    }
+    #[test]
+    fn test_alternative_paths() {
+        let input = std::fs::read_to_string("src/bin/day17/sample.txt").expect("File Not Found!");
+        let map = input.parse::<CityMap>().expect("ops");
+        let target = map.len()-1;
+
+        let paths = map.get_crucible(0, D::Right).k_cheapest_paths(target, 5, 0..3);
+        assert_eq!(paths.len(), 5);
+        // the cheapest paths run through every block at most once
+        let revisits = |p: &CityMapPath| { let mut seen = std::collections::HashSet::new(); !p.iter().all(|(_, b)| seen.insert(b.0)) };
+        assert!(!paths.iter().any(revisits));
+        // turning back allowed, they include walks running through a block more than once
+        assert!(map.get_crucible(0, D::Right).k_cheapest_paths(target, 5, MovementRules::from(0..3).reverse(true)).iter().any(revisits));
+        assert_eq!(paths[0].total_heat_loss(), 102);
+        assert!(paths.windows(2).all(|w| w[0].total_heat_loss() <= w[1].total_heat_loss()));
+        for (i, path) in paths.iter().enumerate() {
+            // paths are distinct, start at the top left, end at the target and add up the heat of the blocks entered
+            assert!(paths[..i].iter().all(|p| p != path));
+            let blocks = path.iter().collect::<Vec<_>>();
+            assert_eq!((blocks[0].1.0, blocks[blocks.len()-1].1), (target, CityBlock(0, D::Right, 0)));
+            assert!(blocks.windows(2).all(|w| w[0].0 == w[1].0 + map[w[0].1.0]));
+        }
+
+        let optimal = map.get_crucible(0, D::Right).optimal_paths(target, 0..3);
+        let total = optimal.total();
+        let optimal = optimal.collect::<Vec<_>>();
+        assert_eq!(total, optimal.len() as u128);
+        assert!(!optimal.is_empty());
+        assert!(optimal.iter().all(|p| p.total_heat_loss() == 102));
+        assert!(optimal.iter().enumerate().all(|(i, p)| optimal[..i].iter().all(|o| o != p)));
+        // every optimal path is among the cheapest ones
+        let tied = map.get_crucible(0, D::Right).k_cheapest_paths(target, optimal.len() + 1, 0..3);
+        assert_eq!(tied.iter().filter(|p| p.total_heat_loss() == 102).count(), optimal.len());
+        assert!(optimal.iter().all(|p| tied.contains(p)));
+
+        let optimal = map.get_crucible(0, D::Right).optimal_paths(target, 4..10).collect::<Vec<_>>();
+        assert!(!optimal.is_empty() && optimal.iter().all(|p| p.total_heat_loss() == 94));
+    }
+    #[test]
+    fn test_zero_heat_blocks() {
+        // the crucible can go round the four blocks in the middle for free
+        let map = "1111\n1001\n1001\n1111".parse::<CityMap>().expect("ops");
+        let target = map.len()-1;
+        let loops_back = |p: &CityMapPath| { let mut seen = std::collections::HashSet::new(); !p.iter().all(|(_, b)| seen.insert(b)) };
+
+        let heat = map.get_crucible(0, D::Right).find_path_to(target, 0..3).map(|p| p.total_heat_loss());
+        assert_eq!(heat, Some(3));
+
+        let optimal = map.get_crucible(0, D::Right).optimal_paths(target, 0..3);
+        let total = optimal.total();
+        let optimal = optimal.collect::<Vec<_>>();
+        assert_eq!(total, optimal.len() as u128);
+        assert!(optimal.len() > 1);
+        assert!(optimal.iter().all(|p| p.total_heat_loss() == 3 && !loops_back(p)));
+        assert!(optimal.iter().enumerate().all(|(i, p)| optimal[..i].iter().all(|o| o != p)));
+
+        let paths = map.get_crucible(0, D::Right).k_cheapest_paths(target, 50, 0..3);
+        assert_eq!(paths.len(), 50);
+        assert!(paths.iter().all(|p| !loops_back(p)));
+        assert!(optimal.iter().all(|p| paths.contains(p)));
+    }
+    #[test]
+    fn test_neighbour_blocks() {
+        let input = std::fs::read_to_string("src/bin/day17/sample.txt").expect("File Not Found!");
+        let map = input.parse::<CityMap>().expect("ops");
+
+        let data = [
+            ((13,D::Right), vec![(D::Right, 14, 2),(D::Up, 0, 1),(D::Down, 26, 1)]),
+            ((13,D::Left), vec![(D::Up, 0, 1),(D::Down, 26, 1)]),
+            ((25,D::Left), vec![(D::Left, 24, 2),(D::Up, 12, 1),(D::Down, 38, 1)]),
+            ((25,D::Right), vec![(D::Up, 12, 1),(D::Down, 38, 1)]),
+            ((168,D::Up),vec![(D::Up, 155, 2),(D::Left, 167, 1)]),
+            ((0,D::Up),vec![(D::Right, 1, 1)]),
+            ((12,D::Right),vec![(D::Down, 25, 1)]),
+            ((156,D::Left),vec![(D::Up, 143, 1)])
+        ];
+
+        for ((pos,dir), out) in data.into_iter() {
+            let crucible = map.get_crucible(pos, dir);
+            let node = CityBlock(pos, dir, 1);
+            let rules = MovementRules::from(1..3);
+            let iter = crucible.neighbour_blocks(node, &rules);
+            iter.enumerate()
+                .for_each(|(i,p)| {
+                    println!("{:?} => {:?}", (pos, dir), (i,p));
+                    assert_eq!((p.1,p.0,p.2),out[i])
+                })
+        }
+    }
+}
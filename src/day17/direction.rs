@@ -0,0 +1,67 @@
+use crate::grid;
+
+/// Heading of a crucible; the four grid directions plus the diagonals in between, clockwise from `Up`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction { Up=0, UpRight, Right, DownRight, Down, DownLeft, Left, UpLeft }
+use Direction as D;
+
+impl Direction {
+    pub const ALL: [Direction; 8] = [D::Up, D::UpRight, D::Right, D::DownRight, D::Down, D::DownLeft, D::Left, D::UpLeft];
+
+    /// Heading after turning clockwise by `eighths` of a full turn, or anti-clockwise when negative
+    pub fn rotate(&self, eighths: isize) -> Direction {
+        D::ALL[(*self as isize + eighths).rem_euclid(8) as usize]
+    }
+    pub fn reverse(&self) -> Direction {
+        self.rotate(4)
+    }
+    /// The two directions at right angle, always in `Up, Down` or `Left, Right` order for grid directions
+    pub fn perpendicular(&self) -> [Direction; 2] {
+        let [a, b] = [self.rotate(-2), self.rotate(2)];
+        if (*self as usize) < 4 { [a, b] } else { [b, a] }
+    }
+    pub fn is_diagonal(&self) -> bool {
+        *self as usize % 2 == 1
+    }
+    /// Eighths of a full turn between the two headings, from 0 to 4
+    pub fn angle(&self, other: Direction) -> usize {
+        let d = (other as isize - *self as isize).rem_euclid(8) as usize;
+        d.min(8 - d)
+    }
+    /// Unit step `(dx, dy)` with `y` growing downwards
+    pub fn offset(&self) -> (isize, isize) {
+        match self {
+            D::Up => (0, -1), D::UpRight => (1, -1), D::Right => (1, 0), D::DownRight => (1, 1),
+            D::Down => (0, 1), D::DownLeft => (-1, 1), D::Left => (-1, 0), D::UpLeft => (-1, -1),
+        }
+    }
+}
+
+impl From<grid::Direction> for Direction {
+    fn from(dir: grid::Direction) -> Self {
+        match dir {
+            grid::Direction::Up => D::Up,
+            grid::Direction::Right => D::Right,
+            grid::Direction::Down => D::Down,
+            grid::Direction::Left => D::Left,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_direction() {
+        assert_eq!(D::Up.rotate(-1), D::UpLeft);
+        assert_eq!(D::UpLeft.rotate(3), D::Right);
+        assert_eq!(D::DownRight.reverse(), D::UpLeft);
+        assert_eq!(D::Right.perpendicular(), [D::Up, D::Down]);
+        assert_eq!(D::Down.perpendicular(), [D::Left, D::Right]);
+        assert_eq!(D::UpRight.perpendicular(), [D::UpLeft, D::DownRight]);
+        assert_eq!((D::Up.angle(D::Left), D::Up.angle(D::DownLeft), D::Left.angle(D::Left)), (2, 3, 0));
+        assert!(D::DownLeft.is_diagonal() && !D::Down.is_diagonal());
+        assert_eq!(D::from(grid::Direction::Left), D::Left);
+    }
+}
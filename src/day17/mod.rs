@@ -0,0 +1,69 @@
+pub mod citymap;
+pub mod direction;
+pub mod crucible;
+pub mod block;
+pub mod path;
+pub mod queue;
+pub mod rules;
+pub mod render;
+
+use std::{collections::BinaryHeap, ops::Range, time::{Duration, Instant}};
+use block::{Heat, QueuedCityBlock, Step};
+use citymap::CityMap;
+use crucible::{SearchStats, Strategy};
+use direction::Direction as D;
+use queue::{BucketQueue, QueueKind};
+use rules::MovementRules;
+use crate::Solution;
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+    type Input = CityMap;
+    type Part1 = Heat;
+    type Part2 = Heat;
+
+    fn parse(input: &str) -> CityMap {
+        input.parse::<CityMap>().expect("ops")
+    }
+    fn part1(map: &CityMap) -> Heat {
+        total_heat_loss(map, 0..3).unwrap()
+    }
+    fn part2(map: &CityMap) -> Heat {
+        total_heat_loss(map, 4..10).unwrap()
+    }
+}
+
+fn total_heat_loss(map: &CityMap, rng: Range<Step>) -> Option<Heat> {
+    map.get_crucible(0, D::Right)
+        .find_path_to(map.len()-1, rng)
+        .map(|path| path.total_heat_loss() )
+}
+
+/// Outcome of one search configuration in [`compare_searches`]
+#[derive(Debug)]
+pub struct SearchRun {
+    pub strategy: Strategy,
+    pub queue: QueueKind,
+    pub heat: Option<Heat>,
+    pub stats: SearchStats,
+    pub elapsed: Duration,
+}
+
+/// Runs every combination of search strategy and priority queue from the top left to the bottom right block
+pub fn compare_searches(map: &CityMap, rules: &MovementRules) -> Vec<SearchRun> {
+    let run = |strategy, queue| {
+        let mut crucible = map.get_crucible(0, D::Right);
+        let t = Instant::now();
+        let (path, stats) = match queue {
+            QueueKind::BinaryHeap => crucible.search::<BinaryHeap<QueuedCityBlock>>(map.len()-1, rules, strategy),
+            QueueKind::Bucket => crucible.search::<BucketQueue>(map.len()-1, rules, strategy),
+        };
+        SearchRun { strategy, queue, heat: path.map(|p| p.total_heat_loss()), stats, elapsed: t.elapsed() }
+    };
+
+    [Strategy::Dijkstra, Strategy::AStar].into_iter()
+        .flat_map(|strategy| [QueueKind::BinaryHeap, QueueKind::Bucket].map(|queue| run(strategy, queue)))
+        .collect()
+}
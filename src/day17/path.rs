@@ -0,0 +1,49 @@
+use std::collections::HashMap;
+use crate::day17::block::{CityBlock, Heat};
+
+/// A route across the city, as the blocks from start to target along with the heat lost up to each block
+#[derive(Debug, Clone, PartialEq)]
+pub struct CityMapPath {
+    blocks: Vec<(Heat, CityBlock)>,
+}
+
+impl CityMapPath {
+    pub fn total_heat_loss(&self) -> Heat {
+        self.blocks.last().map(|&(heat, _)| heat).unwrap_or(0)
+    }
+    /// Follows the chain of blocks stepped from, out of a search's cost map, back from `target`
+    pub fn new(map: HashMap::<CityBlock,(Heat, Option<CityBlock>)>, target: CityBlock) -> CityMapPath {
+        let mut blocks = std::iter::successors(
+            Some((map[&target].0, target)),
+            |(_, block)| map[block].1.map(|parent| (map[&parent].0, parent))
+        ).collect::<Vec<_>>();
+        blocks.reverse();
+        CityMapPath { blocks }
+    }
+    /// A path out of the blocks from start to target, with the heat lost up to each block
+    pub fn from_blocks(blocks: Vec<(Heat, CityBlock)>) -> CityMapPath {
+        CityMapPath { blocks }
+    }
+    pub fn len(&self) -> usize {
+        self.blocks.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.blocks.is_empty()
+    }
+    /// Walks the path backwards, from the target to the start
+    pub fn iter(&self) -> PathIter<'_> {
+        PathIter { blocks: self.blocks.iter().rev() }
+    }
+}
+
+pub struct PathIter<'a> {
+    blocks: std::iter::Rev<std::slice::Iter<'a, (Heat, CityBlock)>>
+}
+
+impl Iterator for PathIter<'_> {
+    type Item = (Heat, CityBlock);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.blocks.next().copied()
+    }
+}
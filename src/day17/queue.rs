@@ -0,0 +1,92 @@
+use std::collections::BinaryHeap;
+use crate::day17::block::{CityBlock, Heat, QueuedCityBlock};
+
+/// Min-priority queue of city blocks, ordered by heat
+pub trait PriorityQueue: Default {
+    fn push(&mut self, heat: Heat, block: CityBlock);
+    fn pop(&mut self) -> Option<(Heat, CityBlock)>;
+}
+
+impl PriorityQueue for BinaryHeap<QueuedCityBlock> {
+    fn push(&mut self, heat: Heat, block: CityBlock) {
+        BinaryHeap::push(self, QueuedCityBlock(heat, block))
+    }
+    fn pop(&mut self) -> Option<(Heat, CityBlock)> {
+        BinaryHeap::pop(self).map(|QueuedCityBlock(heat, block)| (heat, block))
+    }
+}
+
+/// The priority queues a search can run on
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum QueueKind {
+    #[default]
+    BinaryHeap,
+    Bucket,
+}
+
+/// Queue with a bucket of blocks per heat value
+///
+/// Heat is a small number and the search pops blocks in non-decreasing heat order,
+/// hence finding the next block is a scan forward from the last bucket popped rather than a heap operation
+#[derive(Debug, Default)]
+pub struct BucketQueue {
+    buckets: Vec<Vec<CityBlock>>,
+    // lowest bucket that may hold blocks
+    current: usize,
+    len: usize,
+}
+
+impl BucketQueue {
+    pub fn len(&self) -> usize { self.len }
+    pub fn is_empty(&self) -> bool { self.len == 0 }
+}
+
+impl PriorityQueue for BucketQueue {
+    fn push(&mut self, heat: Heat, block: CityBlock) {
+        let idx = heat as usize;
+        if idx >= self.buckets.len() { self.buckets.resize_with(idx + 1, Vec::new) }
+        self.buckets[idx].push(block);
+        // stays correct even when a lower heat than the last popped is pushed
+        self.current = self.current.min(idx);
+        self.len += 1;
+    }
+    fn pop(&mut self) -> Option<(Heat, CityBlock)> {
+        if self.len == 0 { return None }
+        while self.buckets[self.current].is_empty() { self.current += 1 }
+        self.len -= 1;
+        self.buckets[self.current].pop().map(|block| (self.current as Heat, block))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::day17::direction::Direction as D;
+
+    fn drain<Q: PriorityQueue>(mut queue: Q) -> Vec<Heat> {
+        for (heat, pos) in [(5, 0), (1, 1), (3, 2), (1, 3), (9, 4), (0, 5)] {
+            queue.push(heat, CityBlock(pos, D::Right, 0));
+        }
+        let mut out = vec![queue.pop().unwrap().0];
+        queue.push(2, CityBlock(6, D::Right, 0));
+        std::iter::from_fn(|| queue.pop()).for_each(|(heat, _)| out.push(heat));
+        out
+    }
+
+    #[test]
+    fn test_queues() {
+        assert_eq!(drain(BinaryHeap::<QueuedCityBlock>::new()), [0, 1, 1, 2, 3, 5, 9]);
+        assert_eq!(drain(BucketQueue::default()), [0, 1, 1, 2, 3, 5, 9]);
+
+        let mut queue = BucketQueue::default();
+        queue.push(4, CityBlock(0, D::Up, 0));
+        queue.push(2, CityBlock(1, D::Up, 0));
+        assert_eq!(queue.len(), 2);
+        assert_eq!(queue.pop(), Some((2, CityBlock(1, D::Up, 0))));
+        // pushing below the last popped heat is still served first
+        queue.push(1, CityBlock(2, D::Up, 0));
+        assert_eq!(queue.pop(), Some((1, CityBlock(2, D::Up, 0))));
+        assert_eq!(queue.pop(), Some((4, CityBlock(0, D::Up, 0))));
+        assert!(queue.is_empty() && queue.pop().is_none());
+    }
+}
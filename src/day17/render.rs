@@ -0,0 +1,123 @@
+use crate::image::{Raster, Rgb, Svg, HEAT_STOPS};
+use crate::day17::{block::Heat, citymap::CityMap, path::CityMapPath};
+
+// colours of the blocks losing the least and the most heat
+const COOL: Rgb = Rgb(245, 245, 245);
+const HOT: Rgb = Rgb(90, 90, 90);
+
+fn block_colour(heat: Heat) -> Rgb {
+    COOL.lerp(HOT, (heat as f64 - 1.0) / 8.0)
+}
+
+/// Colour of the path at each of its blocks, from start to target, by the heat lost so far
+fn path_colours(path: &CityMapPath) -> Vec<(Rgb, usize)> {
+    let total = path.total_heat_loss().max(1) as f64;
+    let mut colours = path.iter()
+        .map(|(heat, block)| (Rgb::gradient(&HEAT_STOPS, heat as f64 / total), block.0))
+        .collect::<Vec<_>>();
+    colours.reverse();
+    colours
+}
+
+/// Heat map of the city with `path` drawn over it, `cell` pixels per block
+///
+/// Blocks get darker the more heat they lose, while the path goes from yellow at the start to dark red at the target
+/// as heat is lost along the way; blocks are labelled with their heat when there's room for it
+pub fn svg(map: &CityMap, path: &CityMapPath, cell: f64) -> Svg {
+    let mut svg = Svg::new(map.width() as f64 * cell, map.height() as f64 * cell);
+    let centre = |pos| {
+        let c = map.coord_of(pos);
+        ((c.x as f64 + 0.5) * cell, (c.y as f64 + 0.5) * cell)
+    };
+
+    for pos in 0..map.len() {
+        let c = map.coord_of(pos);
+        svg.rect(c.x as f64 * cell, c.y as f64 * cell, cell, cell, block_colour(map[pos]));
+    }
+    let colours = path_colours(path);
+    for w in colours.windows(2) {
+        svg.line(centre(w[0].1), centre(w[1].1), w[1].0, cell * 0.4);
+    }
+    if let (Some(&(start, from)), Some(&(end, to))) = (colours.first(), colours.last()) {
+        svg.circle(centre(from), cell * 0.3, start);
+        svg.circle(centre(to), cell * 0.3, end);
+    }
+    if cell >= 10.0 {
+        for pos in 0..map.len() {
+            svg.text(centre(pos), cell * 0.5, if map[pos] > 5 { Rgb::WHITE } else { Rgb::BLACK }, &map[pos].to_string());
+        }
+    }
+    svg
+}
+
+/// Same as [`svg`] as an image of `cell` by `cell` pixels per block, without the labels
+pub fn raster(map: &CityMap, path: &CityMapPath, cell: usize) -> Raster {
+    let cell = cell.max(1);
+    let mut img = Raster::new(map.width() * cell, map.height() * cell, COOL);
+    for pos in 0..map.len() {
+        let c = map.coord_of(pos);
+        img.fill_rect(c.x * cell, c.y * cell, cell, cell, block_colour(map[pos]));
+    }
+
+    // trace the path with a square brush half a block wide, from the centre of each block to the next
+    let brush = (cell / 2).max(1);
+    let corner = |pos| {
+        let c = map.coord_of(pos);
+        ((c.x * cell + (cell - brush) / 2) as isize, (c.y * cell + (cell - brush) / 2) as isize)
+    };
+    let colours = path_colours(path);
+    if let Some(&(rgb, pos)) = colours.first() {
+        let (x, y) = corner(pos);
+        img.fill_rect(x as usize, y as usize, brush, brush, rgb);
+    }
+    for w in colours.windows(2) {
+        let ((x0, y0), (x1, y1)) = (corner(w[0].1), corner(w[1].1));
+        let steps = (x1 - x0).abs().max((y1 - y0).abs());
+        for s in 1..=steps {
+            let (x, y) = (x0 + (x1 - x0) * s / steps, y0 + (y1 - y0) * s / steps);
+            img.fill_rect(x as usize, y as usize, brush, brush, w[1].0);
+        }
+    }
+    img
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::day17::direction::Direction;
+
+    fn sample() -> (CityMap, CityMapPath) {
+        let input = std::fs::read_to_string("src/bin/day17/sample.txt").expect("File Not Found!");
+        let map = input.parse::<CityMap>().expect("ops");
+        let path = map.get_crucible(0, Direction::Right).find_path_to(map.len()-1, 0..3).expect("ops");
+        (map, path)
+    }
+
+    #[test]
+    fn test_svg() {
+        let (map, path) = sample();
+        let out = svg(&map, &path, 20.0).to_string();
+
+        assert!(out.contains(r#"width="260" height="260""#));
+        assert_eq!(out.matches("<rect ").count(), map.len());
+        assert_eq!(out.matches("<line ").count(), path.len() - 1);
+        assert_eq!(out.matches("<text ").count(), map.len());
+        // the path ends in dark red at the target, in the bottom right corner
+        assert!(out.contains(&format!(r#"<circle cx="250" cy="250" r="6" fill="{}"/>"#, HEAT_STOPS[4])));
+        // no room for labels on small blocks
+        assert_eq!(svg(&map, &path, 4.0).to_string().matches("<text ").count(), 0);
+    }
+
+    #[test]
+    fn test_raster() {
+        let (map, path) = sample();
+        let img = raster(&map, &path, 8);
+
+        assert_eq!((img.width(), img.height()), (13 * 8, 13 * 8));
+        // the corner of a block shows its heat, while the path runs through the middle of the blocks it crosses
+        assert_eq!(img.get(0, 0), Some(block_colour(map[0])));
+        assert_eq!(img.get(2, 4), Some(HEAT_STOPS[0]));
+        assert_eq!(img.get(12 * 8 + 4, 12 * 8 + 4), Some(HEAT_STOPS[4]));
+        assert_eq!(img.get(4, 12 * 8 + 4), Some(block_colour(map[12 * 13])));
+    }
+}
@@ -0,0 +1,120 @@
+use std::ops::Range;
+use crate::day17::{block::{Heat, Step}, direction::Direction};
+
+/// How a crucible is allowed to move across the city blocks
+///
+/// Defaults to the part 1 crucible: at most three straight blocks, free right-angle turns, no reversing nor diagonals
+#[derive(Debug, Clone, PartialEq)]
+pub struct MovementRules {
+    /// straight blocks needed before the crucible can turn
+    pub min_straight: Step,
+    /// straight blocks after which the crucible has to turn
+    pub max_straight: Step,
+    /// per heading override of `max_straight`
+    pub limits: [Option<Step>; 8],
+    /// extra heat lost on every change of heading
+    pub turn_cost: Heat,
+    /// whether the crucible can turn around on the spot
+    pub reverse: bool,
+    /// whether the crucible can also move diagonally
+    pub diagonal: bool,
+}
+
+impl Default for MovementRules {
+    fn default() -> Self {
+        MovementRules::from(0..3)
+    }
+}
+
+/// `min..max` straight blocks, as in the puzzle
+impl From<Range<Step>> for MovementRules {
+    fn from(rng: Range<Step>) -> Self {
+        MovementRules {
+            min_straight: rng.start,
+            max_straight: rng.end,
+            limits: [None; 8],
+            turn_cost: 0,
+            reverse: false,
+            diagonal: false,
+        }
+    }
+}
+
+impl MovementRules {
+    pub fn turn_cost(self, turn_cost: Heat) -> Self {
+        MovementRules { turn_cost, ..self }
+    }
+    pub fn reverse(self, reverse: bool) -> Self {
+        MovementRules { reverse, ..self }
+    }
+    pub fn diagonal(self, diagonal: bool) -> Self {
+        MovementRules { diagonal, ..self }
+    }
+    /// Caps the straight blocks moving towards `dir`
+    pub fn limit(mut self, dir: Direction, max_straight: Step) -> Self {
+        self.limits[dir as usize] = Some(max_straight);
+        self
+    }
+
+    /// Straight blocks after which the crucible has to turn when heading towards `dir`
+    pub fn max_straight(&self, dir: Direction) -> Step {
+        self.limits[dir as usize].unwrap_or(self.max_straight)
+    }
+    /// Headings the crucible can take next, after `step` straight blocks heading towards `dir`
+    ///
+    /// Carrying on straight comes first, followed by right-angle turns, diagonal turns and reversing, when allowed
+    pub fn moves(&self, dir: Direction, step: Step) -> impl Iterator<Item=Direction> + '_ {
+        let diagonals = [-1, 1, -3, 3].map(|eighths| dir.rotate(eighths));
+        std::iter::once(dir)
+            .chain(dir.perpendicular())
+            .chain(diagonals.into_iter().filter(|_| self.diagonal))
+            .chain(std::iter::once(dir.reverse()).filter(|_| self.reverse))
+            // if step < min then move same direction otherwise move all directions
+            .filter(move |&d| d == dir || step >= self.min_straight)
+            // if step == max then drop same direction, and never head where no straight blocks are allowed
+            .filter(move |&d| if d == dir { step < self.max_straight(d) } else { self.max_straight(d) > 0 })
+    }
+    /// Heat lost for changing heading from `from` to `to`; free when the crucible hasn't moved yet
+    pub fn turn_heat(&self, from: Direction, to: Direction, step: Step) -> Heat {
+        if from != to && step > 0 { self.turn_cost } else { 0 }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use Direction as D;
+
+    #[test]
+    fn test_moves() {
+        let rules = MovementRules::from(0..3);
+        assert_eq!(rules, MovementRules::default());
+        assert_eq!(rules.moves(D::Right, 1).collect::<Vec<_>>(), [D::Right, D::Up, D::Down]);
+        assert_eq!(rules.moves(D::Right, 3).collect::<Vec<_>>(), [D::Up, D::Down]);
+
+        let rules = MovementRules::from(4..10);
+        assert_eq!(rules.moves(D::Up, 3).collect::<Vec<_>>(), [D::Up]);
+        assert_eq!(rules.moves(D::Up, 4).collect::<Vec<_>>(), [D::Up, D::Left, D::Right]);
+
+        let rules = MovementRules::from(0..3).reverse(true).limit(D::Up, 1);
+        assert_eq!(rules.moves(D::Up, 1).collect::<Vec<_>>(), [D::Left, D::Right, D::Down]);
+        assert_eq!(rules.moves(D::Down, 1).collect::<Vec<_>>(), [D::Down, D::Left, D::Right, D::Up]);
+        let rules = rules.limit(D::Left, 0);
+        assert_eq!(rules.moves(D::Down, 1).collect::<Vec<_>>(), [D::Down, D::Right, D::Up]);
+
+        let rules = MovementRules::from(0..3).diagonal(true);
+        assert_eq!(
+            rules.moves(D::Right, 0).collect::<Vec<_>>(),
+            [D::Right, D::Up, D::Down, D::UpRight, D::DownRight, D::UpLeft, D::DownLeft]
+        );
+    }
+
+    #[test]
+    fn test_turn_heat() {
+        let rules = MovementRules::default().turn_cost(5);
+        assert_eq!(rules.turn_heat(D::Right, D::Right, 2), 0);
+        assert_eq!(rules.turn_heat(D::Right, D::Down, 2), 5);
+        // choosing the first heading isn't a turn
+        assert_eq!(rules.turn_heat(D::Right, D::Down, 0), 0);
+    }
+}
@@ -0,0 +1,221 @@
+use crate::day18::instruction::{Instruction, InstructionErr, Rgb};
+use crate::day18::polygon::Polygon;
+use crate::day18::position::Position;
+use std::str::FromStr;
+use thiserror::Error;
+
+#[derive(Clone)]
+pub struct DigPlan {
+    pub set: std::rc::Rc<[Instruction]>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Winding { Clockwise, CounterClockwise }
+
+#[derive(Debug, PartialEq, Error)]
+pub enum PlanError {
+    #[error("Trench ends at {0:?} rather than back at the origin")]
+    NotClosed(Position),
+    #[error("Trench runs on lines {0} and {1} meet at {2:?}")]
+    SelfIntersecting(usize, usize, Position),
+    #[error("Colour {1} on line {0} doesn't encode a direction")]
+    Undecodable(usize, Rgb),
+}
+
+/// What a dig plan's trench looks like, ahead of digging it out
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlanReport {
+    /// where the trench ends, back at the origin when it forms a loop
+    pub end: Position,
+    /// runs sharing a cube other than where one leads into the next, as the lines of both instructions and the first cube shared
+    pub crossings: Vec<(usize, usize, Position)>,
+    /// direction the loop runs in, when it's closed
+    pub winding: Option<Winding>,
+}
+
+impl PlanReport {
+    pub fn is_closed(&self) -> bool {
+        self.end == Position(0, 0)
+    }
+    /// The winding of a closed trench that never meets itself, or the first problem found otherwise
+    pub fn check(&self) -> Result<Winding, PlanError> {
+        if let Some(&(a, b, at)) = self.crossings.first() {
+            return Err(PlanError::SelfIntersecting(a, b, at))
+        }
+        self.winding.ok_or(PlanError::NotClosed(self.end))
+    }
+}
+
+impl DigPlan {
+    pub fn iter(&self) -> impl Iterator<Item = &Instruction> + '_ {
+        self.set.iter()
+    }
+    /// The plan hidden in the instructions' colours
+    pub fn decode_rgb(&self) -> DigPlan {
+        DigPlan { set: self.set.iter().map(|i| i.decode_rgb()).collect() }
+    }
+    /// The plan hidden in the instructions' colours, unless a colour's last digit is past the four directions
+    pub fn try_decode_rgb(&self) -> Result<DigPlan, PlanError> {
+        match self.iter().position(|i| i.rgb.2 & 0xf > 3) {
+            Some(n) => Err(PlanError::Undecodable(n + 1, self.set[n].rgb)),
+            None => Ok(self.decode_rgb()),
+        }
+    }
+    pub fn is_clockwise(&self) -> bool {
+        Polygon::new(self.iter()).signed_double_area() > 0
+    }
+    /// The same trench dug clockwise, walking the plan backwards with every direction reversed when it runs the other way
+    pub fn clockwise(&self) -> DigPlan {
+        if self.is_clockwise() { return self.clone() }
+        DigPlan { set: self.set.iter().rev().map(|i| Instruction { dir: i.dir.reverse(), ..i.clone() }).collect() }
+    }
+    /// Checks that the trench closes back on the origin without running into itself, and which way it winds
+    pub fn validate(&self) -> PlanReport {
+        let polygon = Polygon::new(self.iter());
+        let end = polygon.vertices().last().copied().unwrap_or(Position(0, 0));
+        // (line, from, to, instruction) of every run, skipping empty ones
+        let runs = std::iter::once(Position(0, 0))
+            .chain(polygon.vertices().iter().copied())
+            .zip(polygon.vertices().iter().copied())
+            .zip(self.iter())
+            .enumerate()
+            .filter(|(_, (_, i))| i.run > 0)
+            .map(|(n, ((from, to), i))| (n + 1, from, to, i))
+            .collect::<Vec<_>>();
+        let closed = end == Position(0, 0);
+
+        let mut crossings = vec![];
+        for (a, &(line_a, from_a, to_a, ins_a)) in runs.iter().enumerate() {
+            for (b, &(line_b, from_b, to_b, ins_b)) in runs.iter().enumerate().skip(a + 1) {
+                let adjacent = b == a + 1 || (closed && a == 0 && b == runs.len() - 1);
+                if adjacent {
+                    // consecutive runs share a corner, yet they shouldn't double back over each other
+                    if ins_a.dir.reverse() == ins_b.dir {
+                        let corner = if b == a + 1 { to_a } else { from_a };
+                        let mut at = corner;
+                        at.next_mut(if b == a + 1 { ins_b.dir } else { ins_a.dir });
+                        crossings.push((line_a, line_b, at));
+                    }
+                    continue
+                }
+                let (x0, x1) = (from_a.0.min(to_a.0).max(from_b.0.min(to_b.0)), from_a.0.max(to_a.0).min(from_b.0.max(to_b.0)));
+                let (y0, y1) = (from_a.1.min(to_a.1).max(from_b.1.min(to_b.1)), from_a.1.max(to_a.1).min(from_b.1.max(to_b.1)));
+                if x0 <= x1 && y0 <= y1 { crossings.push((line_a, line_b, Position(x0, y0))) }
+            }
+        }
+
+        let winding = closed.then(|| {
+            if polygon.signed_double_area() > 0 { Winding::Clockwise } else { Winding::CounterClockwise }
+        });
+        PlanReport { end, crossings, winding }
+    }
+}
+
+/// A dig plan checked by [`DigPlan::validate`] to close back on the origin without running into itself
+#[derive(Clone)]
+pub struct ValidPlan {
+    plan: DigPlan,
+    winding: Winding,
+}
+
+impl ValidPlan {
+    pub fn plan(&self) -> &DigPlan {
+        &self.plan
+    }
+    pub fn winding(&self) -> Winding {
+        self.winding
+    }
+}
+
+impl TryFrom<DigPlan> for ValidPlan {
+    type Error = PlanError;
+
+    fn try_from(plan: DigPlan) -> Result<Self, Self::Error> {
+        let winding = plan.validate().check()?;
+        Ok(ValidPlan { plan, winding })
+    }
+}
+
+impl FromStr for DigPlan {
+    type Err = InstructionErr;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut set = vec![];
+        for line in s.lines() {
+            set.push(line.parse::<Instruction>()?);
+        }
+        Ok(DigPlan { set: set.into() })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::day18::lagoon::test::load_plan;
+
+    #[test]
+    fn test_digplan_parse() {
+        let inp = std::fs::read_to_string("./src/bin/day18/sample.txt").expect("Ops!");
+        let plan = match inp.parse::<DigPlan>() {
+            Ok(set) => set,
+            Err(e) => panic!("{}", e),
+        };
+
+        let mut iter = plan.iter();
+        inp.lines()
+            .for_each(|line| {
+                let out = &format!("{:?}",iter.next().unwrap());
+                println!("{line} => {out}");
+                assert_eq!(line,out);
+            });
+    }
+
+    #[test]
+    fn test_digplan_is_clockwise() {
+        let plan: DigPlan = match load_plan(None) {
+            Ok(p) => p,
+            Err(e) => panic!("{}", e),
+        };
+
+        match plan.is_clockwise() {
+            true => println!("Clockwise"),
+            false => println!("Counter Clockwise"),
+        }
+        assert!(load_plan(Some("sample.txt".into())).expect("Ops").is_clockwise());
+        assert!(!load_plan(Some("sample4.txt".into())).expect("Ops").is_clockwise());
+    }
+
+    #[test]
+    fn test_digplan_validate() {
+        for f in ["sample.txt", "sample1.txt", "sample2.txt", "sample3.txt"] {
+            let report = load_plan(Some(f.into())).expect("Ops").validate();
+            assert_eq!(report.check(), Ok(Winding::Clockwise), "{f}");
+        }
+        let report = load_plan(Some("sample4.txt".into())).expect("Ops").validate();
+        assert_eq!(report.check(), Ok(Winding::CounterClockwise));
+        let report = load_plan(Some("sample.txt".into())).expect("Ops").decode_rgb().validate();
+        assert_eq!(report.check(), Ok(Winding::Clockwise));
+
+        let plan = |s: &str| s.parse::<DigPlan>().expect("Ops").validate();
+        // a trench left open
+        let report = plan("R 2 (#000000)\nD 2 (#000000)\nL 2 (#000000)");
+        assert_eq!((report.is_closed(), report.winding), (false, None));
+        assert_eq!(report.check(), Err(PlanError::NotClosed(Position(0, 2))));
+        // a run up through the first one, crossing it at (1,0)
+        let report = plan("R 2 (#000000)\nD 1 (#000000)\nL 1 (#000000)\nU 2 (#000000)\nL 1 (#000000)\nD 1 (#000000)");
+        assert_eq!(report.crossings, [(1, 4, Position(1, 0))]);
+        assert_eq!(report.check().map_err(|e| e.to_string()), Err("Trench runs on lines 1 and 4 meet at (1,0)".into()));
+        // doubling back over the last run
+        let report = plan("R 3 (#000000)\nL 1 (#000000)\nD 1 (#000000)\nL 2 (#000000)\nU 1 (#000000)");
+        assert_eq!(report.check(), Err(PlanError::SelfIntersecting(1, 2, Position(2, 0))));
+    }
+
+    #[test]
+    fn test_digplan_try_decode_rgb() {
+        let plan = load_plan(Some("sample.txt".into())).expect("Ops");
+        assert!(plan.try_decode_rgb().expect("Ops").iter().eq(plan.decode_rgb().iter()));
+        // a last digit of 4 and above encodes no direction
+        let plan = "R 2 (#000020)\nD 2 (#000024)".parse::<DigPlan>().expect("Ops");
+        assert_eq!(plan.try_decode_rgb().err(), Some(PlanError::Undecodable(2, Rgb(0, 0, 0x24))));
+    }
+}
@@ -4,7 +4,7 @@ use std::rc::Rc;
 use std::str::FromStr;
 
 #[derive(Debug,PartialEq, Copy, Clone)]
-pub(crate) enum Direction { U = 0, R, D, L }
+pub enum Direction { U = 0, R, D, L }
 
 const TURNS: [Direction; 6] = [
     Direction::L,
@@ -16,6 +16,14 @@ const TURNS: [Direction; 6] = [
 ];
 
 impl Direction {
+    pub fn reverse(&self) -> Direction {
+        match self {
+            Direction::U => Direction::D,
+            Direction::R => Direction::L,
+            Direction::D => Direction::U,
+            Direction::L => Direction::R,
+        }
+    }
     pub fn is_clockwise(&self, last: Direction) -> bool {
         TURNS[*self as usize] == last
     }
@@ -37,19 +45,19 @@ impl Display for Rgb {
 }
 
 #[derive(PartialEq, Clone)]
-pub(crate) struct Instruction {
+pub struct Instruction {
     pub dir: Direction,
     pub run: usize,
     pub rgb: Rgb
 }
 
 impl Instruction {
-    pub(crate) fn decode_rgb(&self) -> Instruction {
+    pub fn decode_rgb(&self) -> Instruction {
         let s = format!("{}", self.rgb);
         Instruction {
             dir: TURNS[usize::from_str(&s[6..=6]).unwrap() + 2],
             run: usize::from_str_radix(&s[1..=5], 16).unwrap(),
-            rgb: Rgb(0,0,0)
+            rgb: self.rgb
         }
     }
 }
@@ -100,7 +108,7 @@ impl Display for Instruction {
 }
 
 #[derive(PartialEq)]
-pub(crate) enum InstructionErr {
+pub enum InstructionErr {
     InvalidDirection(Rc<str>),
     InvalidRunLength(Rc<str>),
     InvalidRGB(Rc<str>),
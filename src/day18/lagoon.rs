@@ -1,5 +1,5 @@
-use crate::instruction::{Direction, Instruction, Rgb};
-use crate::position::{Position, Unit};
+use crate::day18::instruction::{Direction, Instruction, Rgb};
+use crate::day18::position::{Position, Unit};
 use rayon::prelude::*;
 use std::collections::BTreeMap;
 use std::fmt::{Debug, Formatter};
@@ -14,7 +14,7 @@ impl Trench {
     fn b(&self) -> u8 { self.0.2 }
 }
 
-pub(crate) struct Digger {
+pub struct Digger {
     pos: Position,
     last: Option<Direction>,
 }
@@ -39,7 +39,7 @@ impl Digger {
     }
 }
 
-pub(crate) struct Lagoon {
+pub struct Lagoon {
     min: Position,
     max: Position,
     map: BTreeMap<Position, Trench>
@@ -88,7 +88,7 @@ impl Lagoon {
             })
     }
 
-    pub(crate) fn calculate_area(&self) -> usize {
+    pub fn calculate_area(&self) -> usize {
         (self.min.1..=self.max.1)
             .into_par_iter()
             .map(|y| {
@@ -142,7 +142,7 @@ pub mod test {
     use std::rc::Rc;
 
     use super::*;
-    use crate::digging_plan::DigPlan;
+    use crate::day18::digging_plan::DigPlan;
 
     #[test]
     fn test_lagoon_area_rgb() {
@@ -0,0 +1,96 @@
+pub mod digging_plan;
+pub mod instruction;
+pub mod lagoon;
+pub mod polygon;
+pub mod position;
+pub mod render;
+
+use digging_plan::{DigPlan, PlanError, ValidPlan};
+use lagoon::{Digger, Lagoon};
+use position::Position;
+use polygon::Polygon;
+use std::str::FromStr;
+use crate::Solution;
+
+/// The plans of both parts, checked once the plan is parsed
+///
+/// A plan that doesn't form a trench can't be parsed, while the one decoded from its colours is only needed by part 2
+pub struct Plans {
+    pub dug: ValidPlan,
+    pub decoded: Result<ValidPlan, PlanError>,
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+    type Input = Plans;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Plans {
+        let plan = match input.parse::<DigPlan>() {
+            Ok(plan) => plan,
+            Err(e) => panic!("{}",e),
+        };
+        let decoded = plan.try_decode_rgb().and_then(ValidPlan::try_from);
+        match ValidPlan::try_from(plan) {
+            Ok(dug) => Plans { dug, decoded },
+            Err(e) => panic!("{}",e),
+        }
+    }
+    fn part1(plans: &Plans) -> usize {
+        lagoon_total(&plans.dug, Engine::Shoelace)
+    }
+    fn part2(plans: &Plans) -> usize {
+        match &plans.decoded {
+            Ok(trench) => lagoon_total(trench, Engine::Shoelace),
+            Err(e) => panic!("{}",e),
+        }
+    }
+}
+
+/// How the lagoon's volume is worked out
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Engine {
+    /// digging out every trench cube into a `Lagoon` and flood filling it line by line
+    ScanLine,
+    /// the shoelace formula and Pick's theorem over the corners of the trench
+    #[default]
+    Shoelace,
+}
+
+impl FromStr for Engine {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "scanline" => Ok(Engine::ScanLine),
+            "shoelace" => Ok(Engine::Shoelace),
+            _ => Err(format!("Unknown engine {s:?}; expected `scanline` or `shoelace`")),
+        }
+    }
+}
+
+/// Cubes of lava the lagoon holds once dug along the trench
+pub fn lagoon_total(trench: &ValidPlan, engine: Engine) -> usize {
+    match engine {
+        Engine::ScanLine => dig_lagoon(trench.plan()),
+        Engine::Shoelace => Polygon::new(trench.plan().iter()).total().expect("a trench that never meets itself encloses its interior"),
+    }
+}
+
+fn dig_lagoon(plan: &DigPlan) -> usize {
+    let mut lagoon = Lagoon::default();
+    let mut digger = Digger::new(Position(0, 0));
+
+    // flood filling expects a clockwise trench, otherwise it fills the outside of the loop
+    let plan = plan.clockwise();
+
+    let total = plan
+        .iter()
+        .map(|ins| digger.dig(&mut lagoon, ins))
+        .sum::<usize>();
+
+    total + lagoon.calculate_area()
+}
@@ -0,0 +1,134 @@
+use crate::day18::instruction::{Direction, Instruction};
+use crate::day18::position::Position;
+
+/// The trench loop as the corners the digger turns at, an alternative to digging out every cube of a `Lagoon`
+///
+/// Memory and time only grow with the number of instructions rather than with the length of the trench,
+/// so long runs like those of the decoded colours cost nothing extra
+#[derive(Debug, Clone, PartialEq)]
+pub struct Polygon {
+    vertices: Vec<Position>,
+    perimeter: usize,
+}
+
+impl Polygon {
+    /// Follows the instructions from the origin, recording a vertex at the end of every run
+    pub fn new<'a>(instructions: impl Iterator<Item=&'a Instruction>) -> Polygon {
+        let mut pos = Position(0, 0);
+        let mut perimeter = 0;
+        let vertices = instructions
+            .map(|i| {
+                let run = i.run as isize;
+                match i.dir {
+                    Direction::U => pos.1 -= run,
+                    Direction::R => pos.0 += run,
+                    Direction::D => pos.1 += run,
+                    Direction::L => pos.0 -= run,
+                }
+                perimeter += i.run;
+                pos
+            })
+            .collect();
+        Polygon { vertices, perimeter }
+    }
+    pub fn vertices(&self) -> &[Position] {
+        &self.vertices
+    }
+    /// Cubes dug along the trench
+    pub fn perimeter(&self) -> usize {
+        self.perimeter
+    }
+    /// Twice the area enclosed by the path through the centre of the trench cubes, per the shoelace formula;
+    /// positive when the path runs clockwise, as `y` grows downwards
+    pub fn signed_double_area(&self) -> i128 {
+        let n = self.vertices.len();
+        (0..n)
+            .map(|i| {
+                let (Position(x1, y1), Position(x2, y2)) = (self.vertices[i], self.vertices[(i + 1) % n]);
+                x1 as i128 * y2 as i128 - x2 as i128 * y1 as i128
+            })
+            .sum::<i128>()
+    }
+    pub fn double_area(&self) -> u128 {
+        self.signed_double_area().unsigned_abs()
+    }
+    /// Cubes enclosed by the trench, per Pick's theorem `A = I + B/2 - 1`
+    ///
+    /// The theorem only holds for a trench that never meets itself; `None` when one doubling back over
+    /// itself, such as `R 2`, `L 2`, leaves fewer cubes than it runs along
+    pub fn interior(&self) -> Option<usize> {
+        let interior = (self.double_area() as i128 + 2 - self.perimeter as i128) / 2;
+        usize::try_from(interior).ok()
+    }
+    /// Cubes of lava the lagoon holds, counting both the trench and its interior
+    pub fn total(&self) -> Option<usize> {
+        self.interior().map(|interior| interior + self.perimeter)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::day18::{Day18, Engine, lagoon_total, digging_plan::{DigPlan, PlanError, ValidPlan}, lagoon::test::load_plan};
+    use crate::Solution;
+
+    #[test]
+    fn test_polygon() {
+        let plan = load_plan(Some("sample.txt".into())).expect("Ops");
+        let polygon = Polygon::new(plan.iter());
+
+        assert_eq!(polygon.vertices().len(), 14);
+        assert_eq!(polygon.vertices()[..3], [Position(6, 0), Position(6, 5), Position(4, 5)]);
+        assert_eq!(polygon.vertices().last(), Some(&Position(0, 0)));
+        assert_eq!((polygon.perimeter(), polygon.double_area()), (38, 84));
+        assert_eq!((polygon.interior(), polygon.total()), (Some(24), Some(62)));
+
+        let decoded = plan.iter().map(|i| i.decode_rgb()).collect::<Vec<_>>();
+        assert_eq!(Polygon::new(decoded.iter()).total(), Some(952408144115));
+    }
+
+    #[test]
+    fn test_polygon_doubling_back() {
+        let plan = "R 2 (#000000)\nL 2 (#000000)".parse::<DigPlan>().expect("Ops");
+        let polygon = Polygon::new(plan.iter());
+        assert_eq!((polygon.double_area(), polygon.perimeter()), (0, 4));
+        assert_eq!(polygon.interior(), None);
+        // hence no trench to measure
+        assert_eq!(ValidPlan::try_from(plan).err(), Some(PlanError::SelfIntersecting(1, 2, Position(1, 0))));
+
+        // parsed fine, but part 2 has no trench to measure
+        let plans = Day18::parse(&std::fs::read_to_string("src/bin/day18/sample4.txt").expect("Ops"));
+        assert_eq!(Day18::part1(&plans), 45);
+        assert_eq!(plans.decoded.err().map(|e| e.to_string()), Some("Trench runs on lines 9 and 10 meet at (3074835,2572687)".into()));
+    }
+
+    #[test]
+    fn test_scanline_counter_clockwise() {
+        // sample4.txt runs counter-clockwise; flooding it as dug used to give 36 cubes
+        let plan = load_plan(Some("sample4.txt".into())).expect("Ops");
+        assert!(!plan.is_clockwise() && plan.clockwise().is_clockwise());
+        assert_eq!(lagoon_total(&ValidPlan::try_from(plan).expect("Ops"), Engine::ScanLine), 45);
+
+        // the sample walked backwards holds as much lava either way
+        let plan = load_plan(Some("sample.txt".into())).expect("Ops");
+        let reversed = DigPlan { set: plan.set.iter().rev().map(|i| Instruction { dir: i.dir.reverse(), ..i.clone() }).collect() };
+        assert!(!reversed.is_clockwise());
+        assert_eq!(lagoon_total(&ValidPlan::try_from(reversed).expect("Ops"), Engine::ScanLine), 62);
+    }
+
+    #[test]
+    fn test_engines_agree() {
+        let mut files = std::fs::read_dir("./src/bin/day18")
+            .expect("Ops")
+            .filter_map(|e| e.ok()?.file_name().into_string().ok())
+            .filter(|f| f.starts_with("sample") && f.ends_with(".txt"))
+            .collect::<Vec<_>>();
+        files.sort();
+        assert!(files.len() >= 5);
+
+        for f in files {
+            let trench = ValidPlan::try_from(load_plan(Some(f.clone())).expect("Ops")).expect("Ops");
+            assert_eq!(lagoon_total(&trench, Engine::Shoelace), lagoon_total(&trench, Engine::ScanLine), "{f}");
+        }
+    }
+}
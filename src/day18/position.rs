@@ -1,13 +1,13 @@
-use crate::instruction::Direction;
+use crate::day18::instruction::Direction;
 use std::{
     cmp::Ordering,
     fmt::{Debug, Formatter},
 };
 
-pub(crate) type Unit = isize;
+pub type Unit = isize;
 
 #[derive(Eq, PartialEq, Clone, Copy)]
-pub(crate) struct Position(pub Unit, pub Unit);
+pub struct Position(pub Unit, pub Unit);
 
 impl Debug for Position {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
@@ -28,7 +28,7 @@ impl Ord for Position {
 }
 
 impl Position {
-    pub(crate) fn next_mut(&mut self, dir: Direction) -> &mut Self {
+    pub fn next_mut(&mut self, dir: Direction) -> &mut Self {
         match dir {
             Direction::U => self.1 -= 1,
             Direction::R => self.0 += 1,
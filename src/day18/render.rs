@@ -0,0 +1,146 @@
+use crate::image::{self, Raster, Svg};
+use crate::day18::{instruction::{Instruction, Rgb}, polygon::Polygon, position::Position};
+
+const GROUND: image::Rgb = image::Rgb::WHITE;
+const LAVA: image::Rgb = image::Rgb(96, 96, 96);
+
+impl From<Rgb> for image::Rgb {
+    fn from(Rgb(r, g, b): Rgb) -> Self {
+        image::Rgb(r, g, b)
+    }
+}
+
+/// The trench runs of a plan laid out to fit within a `size` by `size` picture
+///
+/// Trench cubes are squares of `scale` pixels, fractions of a pixel for the huge part 2 lagoons,
+/// with the picture starting at the leftmost column and topmost row of cubes dug
+struct Layout {
+    edges: Vec<(Position, Position, Rgb)>,
+    min: Position,
+    scale: f64,
+    width: f64,
+    height: f64,
+}
+
+impl Layout {
+    fn new(instructions: &[Instruction], size: f64) -> Layout {
+        let vertices = Polygon::new(instructions.iter()).vertices().to_vec();
+        let edges = std::iter::once(Position(0, 0)).chain(vertices.iter().copied())
+            .zip(vertices.iter().copied())
+            .zip(instructions)
+            .map(|((from, to), i)| (from, to, i.rgb))
+            .collect::<Vec<_>>();
+
+        let (mut min, mut max) = (Position(0, 0), Position(0, 0));
+        for &Position(x, y) in &vertices {
+            (min.0, min.1) = (min.0.min(x), min.1.min(y));
+            (max.0, max.1) = (max.0.max(x), max.1.max(y));
+        }
+        let (w, h) = ((max.0 - min.0 + 1) as f64, (max.1 - min.1 + 1) as f64);
+        let scale = size / w.max(h);
+        Layout { edges, min, scale, width: (w * scale).ceil(), height: (h * scale).ceil() }
+    }
+    /// Pixel coordinates of the centre of the cube at `pos`
+    fn to_pixel(&self, Position(x, y): Position) -> (f64, f64) {
+        (((x - self.min.0) as f64 + 0.5) * self.scale, ((y - self.min.1) as f64 + 0.5) * self.scale)
+    }
+    /// Cube coordinates at the pixel coordinates `px`, as a fraction of a cube
+    fn to_cube(&self, px: f64) -> f64 {
+        px / self.scale - 0.5
+    }
+    /// The ranges of row `y`, in cube coordinates, lying inside the trench loop, by the even-odd rule
+    fn inside(&self, y: f64) -> Vec<(f64, f64)> {
+        let mut xs = self.edges.iter()
+            .filter(|(from, to, _)| from.0 == to.0)
+            .filter(|(from, to, _)| (from.1.min(to.1) as f64) <= y && y < from.1.max(to.1) as f64)
+            .map(|(from, ..)| from.0 as f64)
+            .collect::<Vec<_>>();
+        xs.sort_by(f64::total_cmp);
+        xs.chunks_exact(2).map(|p| (p[0], p[1])).collect()
+    }
+    fn brush(&self) -> f64 {
+        self.scale.max(1.0)
+    }
+}
+
+/// The lagoon as an SVG fitting within `size` pixels, filled with lava and outlined with the colour of each trench run
+pub fn svg(instructions: &[Instruction], size: f64) -> Svg {
+    let layout = Layout::new(instructions, size);
+    let mut svg = Svg::new(layout.width, layout.height);
+
+    svg.rect(0.0, 0.0, layout.width, layout.height, GROUND);
+    svg.polygon(layout.edges.iter().map(|&(from, ..)| layout.to_pixel(from)), LAVA);
+    for &(from, to, rgb) in &layout.edges {
+        svg.line(layout.to_pixel(from), layout.to_pixel(to), rgb.into(), layout.brush());
+    }
+    svg
+}
+
+/// Same as [`svg`] as an image, with a trench cube `size / extent` pixels wide, or at least a pixel wide
+pub fn raster(instructions: &[Instruction], size: usize) -> Raster {
+    let layout = Layout::new(instructions, size as f64);
+    let mut img = Raster::new(layout.width as usize, layout.height as usize, GROUND);
+
+    // fill the pixels whose centre lies within the loop through the centres of the trench cubes
+    for py in 0..img.height() {
+        for (x0, x1) in layout.inside(layout.to_cube(py as f64 + 0.5) + layout.min.1 as f64) {
+            let (x0, x1) = (x0 - layout.min.0 as f64, x1 - layout.min.0 as f64);
+            let from = ((x0 + 0.5) * layout.scale - 0.5).ceil().max(0.0) as usize;
+            let to = ((x1 + 0.5) * layout.scale - 0.5).floor().max(0.0) as usize;
+            if from <= to { img.fill_rect(from, py, to - from + 1, 1, LAVA) }
+        }
+    }
+    // paint the trench runs on top, a cube wide
+    let brush = layout.brush();
+    for &(from, to, rgb) in &layout.edges {
+        let ((x0, y0), (x1, y1)) = (layout.to_pixel(from), layout.to_pixel(to));
+        let (x, y) = ((x0.min(x1) - brush / 2.0).max(0.0), (y0.min(y1) - brush / 2.0).max(0.0));
+        let (w, h) = ((x0 - x1).abs() + brush, (y0 - y1).abs() + brush);
+        img.fill_rect(x.round() as usize, y.round() as usize, w.round() as usize, h.round() as usize, rgb.into());
+    }
+    img
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::day18::lagoon::test::load_plan;
+
+    #[test]
+    fn test_raster() {
+        let plan = load_plan(Some("sample.txt".into())).expect("Ops");
+        let instructions = plan.iter().cloned().collect::<Vec<_>>();
+
+        // 7 x 10 cubes, at 3 pixels each
+        let img = raster(&instructions, 30);
+        assert_eq!((img.width(), img.height()), (21, 30));
+        let cube = |x: usize, y: usize| img.get(x * 3 + 1, y * 3 + 1);
+        // the first run to the right is painted #70c710 with lava below it, while (0,3) lies outside the trench
+        assert_eq!(cube(3, 0), Some(image::Rgb(0x70, 0xc7, 0x10)));
+        assert_eq!(cube(3, 1), Some(LAVA));
+        assert_eq!(cube(0, 3), Some(GROUND));
+        // as many lava and trench cubes as the lagoon holds
+        let dug = (0..7).flat_map(|x| (0..10).map(move |y| (x, y))).filter(|&(x, y)| cube(x, y) != Some(GROUND)).count();
+        assert_eq!(dug, 62);
+
+        // the decoded plan keeps its colours, squeezed into a much smaller picture
+        let decoded = instructions.iter().map(|i| i.decode_rgb()).collect::<Vec<_>>();
+        let img = raster(&decoded, 100);
+        assert_eq!(img.width().max(img.height()), 100);
+        // the first run sets off right from the top left corner, where the last one ends
+        assert_eq!(img.get(0, 0), Some(image::Rgb(0x7a, 0x21, 0xe3)));
+        assert_eq!(img.get(20, 0), Some(image::Rgb(0x70, 0xc7, 0x10)));
+    }
+
+    #[test]
+    fn test_svg() {
+        let plan = load_plan(Some("sample.txt".into())).expect("Ops");
+        let instructions = plan.iter().cloned().collect::<Vec<_>>();
+
+        let out = svg(&instructions, 100.0).to_string();
+        assert!(out.contains(r#"width="70" height="100""#));
+        assert_eq!(out.matches("<line ").count(), instructions.len());
+        assert!(out.contains(r##"<line x1="5" y1="5" x2="65" y2="5" stroke="#70c710" stroke-width="10""##));
+        assert!(out.contains(r##"<polygon points="5,5 65,5 65,55 "##));
+    }
+}
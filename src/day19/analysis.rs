@@ -0,0 +1,267 @@
+use crate::day19::{
+    part::{Ratings, ALL_RATINGS},
+    rule::{Action, Rule},
+    system::SortingSystem,
+    workflow::Workflow,
+};
+use std::{collections::{HashMap, HashSet}, fmt::Display, rc::Rc};
+
+/// A problem, or a simplification, found in a set of workflows without sorting any part
+#[derive(Debug, Clone, PartialEq)]
+pub enum Diagnostic {
+    /// the workflow parts start from isn't defined
+    UnknownStart(Rc<str>),
+    /// rule `index` of `workflow` sends parts to `target`, which isn't defined
+    UndefinedWorkflow { workflow: Rc<str>, index: usize, target: Rc<str> },
+    /// no part starting out can ever get to `workflow`
+    Unreachable(Rc<str>),
+    /// workflows sending parts round in a loop, in the order parts can go round
+    Cycle(Vec<Rc<str>>),
+    /// rule `index` of `workflow` never applies, as earlier rules take every part it would
+    ShadowedRule { workflow: Rc<str>, index: usize, rule: Rc<str> },
+    /// every part going through `workflow` ends up with the same `action`
+    SameAction { workflow: Rc<str>, action: Action },
+}
+
+impl Display for Diagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Diagnostic::UnknownStart(wf) => write!(f, "Starting workflow [{wf}] isn't defined"),
+            Diagnostic::UndefinedWorkflow { workflow, index, target } =>
+                write!(f, "Rule #{} of [{workflow}] sends parts to [{target}], which isn't defined", index + 1),
+            Diagnostic::Unreachable(wf) => write!(f, "Workflow [{wf}] is never reached"),
+            Diagnostic::Cycle(wfs) => {
+                let names = wfs.iter().chain(wfs.first()).map(|w| format!("[{w}]")).collect::<Vec<_>>();
+                write!(f, "Parts can loop forever through {}", names.join(" -> "))
+            }
+            Diagnostic::ShadowedRule { workflow, index, rule } =>
+                write!(f, "Rule #{} of [{workflow}] `{rule}` never applies, earlier rules take all its parts", index + 1),
+            Diagnostic::SameAction { workflow, action } =>
+                write!(f, "Every part going through [{workflow}] ends up {action:?}"),
+        }
+    }
+}
+
+/// Checks the workflows reached from `start`, and those that aren't, returning problems in the order
+/// unknown start, undefined workflows, unreachable workflows, cycles, shadowed rules and workflows with a single outcome
+pub fn analyse(system: &SortingSystem, start: &str) -> Vec<Diagnostic> {
+    let mut workflows = system.workflows().collect::<Vec<_>>();
+    workflows.sort_by_key(|w| w.key());
+    let analyser = Analyser { system, live: workflows.iter().map(|w| (w.key(), liveness(w))).collect() };
+    let mut out = vec![];
+
+    if system.get(start).is_none() {
+        out.push(Diagnostic::UnknownStart(start.into()));
+    }
+    for wf in &workflows {
+        out.extend(
+            targets(wf)
+                .filter(|(_, t)| system.get(t).is_none())
+                .map(|(index, target)| Diagnostic::UndefinedWorkflow { workflow: wf.key(), index, target: target.clone() })
+        );
+    }
+    let reached = analyser.reachable(start);
+    out.extend(workflows.iter().filter(|w| !reached.contains(&w.key())).map(|w| Diagnostic::Unreachable(w.key())));
+    out.extend(analyser.cycles(&workflows).into_iter().map(Diagnostic::Cycle));
+    for wf in &workflows {
+        out.extend(
+            wf.rules().iter().enumerate()
+                .filter(|&(i, _)| !analyser.live[&wf.key()].rules[i])
+                .map(|(index, rule)| Diagnostic::ShadowedRule { workflow: wf.key(), index, rule: format!("{rule:?}").into() })
+        );
+    }
+    let mut fates = HashMap::new();
+    for wf in &workflows {
+        if let Some(action) = analyser.fate(&wf.key(), &mut fates) {
+            out.push(Diagnostic::SameAction { workflow: wf.key(), action });
+        }
+    }
+    out
+}
+
+/// Rules of a workflow sending parts to another workflow, along with their position
+fn targets(wf: &Workflow) -> impl Iterator<Item = (usize, &Rc<str>)> {
+    wf.rules().iter().enumerate().filter_map(|(i, rule)| match rule {
+        Rule::ConAct(_, Action::WorkFlow(t)) | Rule::Act(Action::WorkFlow(t)) => Some((i, t)),
+        _ => None,
+    })
+}
+
+/// Which rules of a workflow get any parts at all, given every possible rating enters the workflow
+struct Liveness {
+    rules: Vec<bool>,
+    // some parts fail every rule
+    falls_through: bool,
+}
+
+fn liveness(wf: &Workflow) -> Liveness {
+    let mut residual: Vec<Ratings> = vec![ALL_RATINGS];
+    let rules = wf.rules().iter()
+        .map(|rule| match rule {
+            Rule::ConAct(c, _) => {
+                let (target, remaining) = c.partition_all(&residual);
+                residual = remaining;
+                !target.is_empty()
+            }
+            Rule::Act(_) => !std::mem::take(&mut residual).is_empty(),
+        })
+        .collect();
+    Liveness { rules, falls_through: !residual.is_empty() }
+}
+
+struct Analyser<'a> {
+    system: &'a SortingSystem,
+    live: HashMap<Rc<str>, Liveness>,
+}
+
+impl Analyser<'_> {
+    /// Defined workflows a workflow can send parts to, skipping its shadowed rules
+    fn next(&self, wf: &Workflow) -> Vec<Rc<str>> {
+        targets(wf)
+            .filter(|&(i, t)| self.live[&wf.key()].rules[i] && self.system.get(t).is_some())
+            .map(|(_, t)| t.clone())
+            .collect()
+    }
+    fn reachable(&self, start: &str) -> HashSet<Rc<str>> {
+        let mut reached = HashSet::new();
+        let mut queue = self.system.get(start).into_iter().collect::<Vec<_>>();
+        while let Some(wf) = queue.pop() {
+            if !reached.insert(wf.key()) { continue }
+            queue.extend(self.next(wf).iter().filter_map(|t| self.system.get(t)));
+        }
+        reached
+    }
+    /// Strongly connected workflows, per Tarjan's algorithm, that parts can go round
+    fn cycles(&self, workflows: &[&Workflow]) -> Vec<Vec<Rc<str>>> {
+        struct Tarjan<'a, 'b> {
+            analyser: &'b Analyser<'a>,
+            index: HashMap<Rc<str>, (usize, usize)>,
+            stack: Vec<Rc<str>>,
+            on_stack: HashSet<Rc<str>>,
+            cycles: Vec<Vec<Rc<str>>>,
+        }
+        impl Tarjan<'_, '_> {
+            fn visit(&mut self, wf: &Rc<str>) -> usize {
+                let order = self.index.len();
+                self.index.insert(wf.clone(), (order, order));
+                self.stack.push(wf.clone());
+                self.on_stack.insert(wf.clone());
+
+                let mut low = order;
+                let next = self.analyser.next(self.analyser.system.get(wf).expect("defined workflow"));
+                for t in &next {
+                    match self.index.get(t) {
+                        None => low = low.min(self.visit(t)),
+                        Some(&(o, _)) if self.on_stack.contains(t) => low = low.min(o),
+                        _ => (),
+                    }
+                }
+                self.index.insert(wf.clone(), (order, low));
+
+                if low == order {
+                    let at = self.stack.iter().rposition(|w| w == wf).expect("on the stack");
+                    let scc = self.stack.split_off(at);
+                    scc.iter().for_each(|w| { self.on_stack.remove(w); });
+                    if scc.len() > 1 || next.contains(wf) { self.cycles.push(scc) }
+                }
+                low
+            }
+        }
+
+        let mut tarjan = Tarjan { analyser: self, index: HashMap::new(), stack: vec![], on_stack: HashSet::new(), cycles: vec![] };
+        for wf in workflows {
+            if !tarjan.index.contains_key(&wf.key()) { tarjan.visit(&wf.key()); }
+        }
+        tarjan.cycles
+    }
+    /// The action every part going through the workflow ends up with, if it's always the same one
+    fn fate(&self, name: &Rc<str>, fates: &mut HashMap<Rc<str>, Option<Action>>) -> Option<Action> {
+        if let Some(fate) = fates.get(name) { return fate.clone() }
+        // a workflow within a loop, or one left undefined, can't be settled
+        fates.insert(name.clone(), None);
+        let wf = self.system.get(name)?;
+
+        let live = &self.live[name];
+        // parts failing every rule are dropped rather than accepted or rejected
+        if live.falls_through { return None }
+        let mut actions = wf.rules().iter()
+            .zip(&live.rules)
+            .filter(|&(_, &l)| l)
+            .map(|(rule, _)| {
+                let (Rule::ConAct(_, action) | Rule::Act(action)) = rule;
+                match action {
+                    Action::WorkFlow(next) => self.fate(next, fates),
+                    action => Some(action.clone()),
+                }
+            });
+        let first = actions.next().flatten();
+        let fate = if first.is_some() && actions.all(|a| a == first) { first } else { None };
+        fates.insert(name.clone(), fate.clone());
+        fate
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::day19::parse_puzzle_data;
+
+    #[test]
+    fn test_analyse_sample() {
+        let (_, system) = parse_puzzle_data("src/bin/day19/sample.txt");
+        let diagnostics = analyse(&system, "in");
+
+        // lnx{m>1548:A,A} always accepts, so qs{s>3448:A,lnx} does too, while gd{a>3333:R,R} always rejects
+        assert_eq!(
+            diagnostics,
+            ["gd", "lnx", "qs"].map(|w| Diagnostic::SameAction {
+                workflow: w.into(),
+                action: if w == "gd" { Action::Reject } else { Action::Accept }
+            })
+        );
+        assert!(analyse(&system, "out").contains(&Diagnostic::UnknownStart("out".into())));
+    }
+
+    #[test]
+    fn test_analyse() {
+        let system = "in{x>10:one,m<5:two,x>20:R,three}\n\
+            one{a>5:two,s!=3:one,R}\n\
+            two{x<100:A,x<50:R,A}\n\
+            three{m>=5:five,four}\n\
+            four{x<5:R,x>=5:R}\n\
+            lost{A}"
+            .parse::<SortingSystem>()
+            .expect("Ops");
+        let diagnostics = analyse(&system, "in");
+
+        assert_eq!(
+            diagnostics,
+            [
+                Diagnostic::UndefinedWorkflow { workflow: "three".into(), index: 0, target: "five".into() },
+                Diagnostic::Unreachable("lost".into()),
+                Diagnostic::Cycle(vec!["one".into()]),
+                Diagnostic::ShadowedRule { workflow: "in".into(), index: 2, rule: "x>20:R".into() },
+                Diagnostic::ShadowedRule { workflow: "two".into(), index: 1, rule: "x<50:R".into() },
+                // the conditions of `four` cover every part between them
+                Diagnostic::SameAction { workflow: "four".into(), action: Action::Reject },
+                Diagnostic::SameAction { workflow: "lost".into(), action: Action::Accept },
+                Diagnostic::SameAction { workflow: "two".into(), action: Action::Accept },
+            ]
+        );
+        assert_eq!(diagnostics[0].to_string(), "Rule #1 of [three] sends parts to [five], which isn't defined");
+        assert_eq!(diagnostics[2].to_string(), "Parts can loop forever through [one] -> [one]");
+    }
+
+    #[test]
+    fn test_analyse_cycles() {
+        let system = "in{x>10:a,b}\na{m<5:b,R}\nb{s>5:c,A}\nc{a>1:a,R}\nd{x>1:e,A}\ne{d}"
+            .parse::<SortingSystem>()
+            .expect("Ops");
+        let mut cycles = analyse(&system, "in")
+            .into_iter()
+            .filter_map(|d| match d { Diagnostic::Cycle(wfs) => Some(wfs), _ => None })
+            .collect::<Vec<_>>();
+        cycles.iter_mut().for_each(|c| c.sort());
+        assert_eq!(cycles, [vec!["a".into(), "b".into(), "c".into()], vec![Rc::<str>::from("d"), "e".into()]]);
+    }
+}
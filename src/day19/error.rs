@@ -0,0 +1,43 @@
+/// Workflow parsing Error Codes
+///
+use thiserror::Error;
+
+#[derive(Debug, Clone, Copy, PartialEq, Error)]
+pub enum ErrorKind {
+    #[error("unknown part category, expected one of x, m, a, s")]
+    Category,
+    #[error("unknown comparison, expected one of <, <=, >, >=, ==, !=")]
+    Operand,
+    #[error("invalid rating value")]
+    Rating,
+    #[error("malformed condition, expected comparisons joined by '&&' or '||' with balanced brackets")]
+    Condition,
+    #[error("invalid workflow name")]
+    Name,
+    #[error("malformed rule, expected <condition>:<action> or <action>")]
+    Rule,
+    #[error("malformed workflow, expected <name>{{<rules>}}")]
+    Workflow,
+}
+
+// Top level Error exposed to main; `line` & `column` are 1-based positions of `text` within the input
+#[derive(Debug, Clone, PartialEq, Error)]
+#[error("Line {line}, column {column}: {kind} [{text}]")]
+pub struct ParseError {
+    pub kind: ErrorKind,
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+}
+
+impl ParseError {
+    pub(crate) fn new(kind: ErrorKind, column: usize, text: &str) -> ParseError {
+        ParseError { kind, line: 1, column, text: text.to_string() }
+    }
+    /// Moves the error position when the parsed text is embedded in a larger input
+    pub(crate) fn shift(mut self, lines: usize, columns: usize) -> ParseError {
+        self.line += lines;
+        self.column += columns;
+        self
+    }
+}
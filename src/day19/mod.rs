@@ -0,0 +1,63 @@
+pub mod workflow;
+pub mod rule;
+pub mod part;
+pub mod system;
+pub mod error;
+pub mod tree;
+pub mod analysis;
+pub mod optimise;
+
+use std::rc::Rc;
+use system::SortingSystem;
+use part::{Part,Unit};
+use rule::Action;
+use crate::Solution;
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+    type Input = (Rc<[Part]>, SortingSystem);
+    type Part1 = Unit;
+    type Part2 = Unit;
+
+    fn parse(input: &str) -> Self::Input {
+        parse(input)
+    }
+    fn part1((parts, system): &Self::Input) -> Unit {
+        parts.iter()
+            .filter(|&&part|
+                system.process_part(part, "in") == Some(Action::Accept)
+            )
+            .map(|part| part.sum())
+            .sum::<Unit>()
+    }
+    fn part2((_, system): &Self::Input) -> Unit {
+        system.total_combinations("in", &[1..4001, 1..4001, 1..4001, 1..4001])
+    }
+}
+
+fn parse(inp: &str) -> (Rc<[Part]>, SortingSystem) {
+    let mut split = inp.split("\n\n");
+    let wfs = split
+        .next()
+        .unwrap()
+        .parse::<SortingSystem>()
+        .expect("Failed to parse workflows");
+
+    let parts = split
+        .next()
+        .unwrap()
+        .lines()
+        .map(|line| line.parse::<Part>().expect("msg") )
+        .collect::<Rc<[Part]>>();
+
+    (parts,wfs)
+}
+
+#[cfg(test)]
+fn parse_puzzle_data(file: &str) -> (Rc<[Part]>, SortingSystem) {
+    let inp = std::fs::read_to_string(file)
+        .expect("cannot load data file");
+    parse(&inp)
+}
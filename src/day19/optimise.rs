@@ -0,0 +1,215 @@
+use crate::day19::{
+    part::{Ratings, ALL_RATINGS},
+    rule::{Action, Condition, Rule},
+    system::SortingSystem,
+    workflow::Workflow,
+};
+use std::{collections::{HashMap, HashSet}, rc::Rc};
+
+type Rules = HashMap<Rc<str>, Vec<Rule>>;
+
+/// Rewrites the workflows reached from `start` into fewer and shorter ones sorting parts rated
+/// from 1 to 4000 exactly the same way, by repeating until nothing changes:
+/// * dropping rules that never apply, or that apply to all parts left by earlier rules
+/// * merging adjacent rules with the same action
+/// * sending parts straight on from workflows that do nothing but send them on
+/// * inlining workflows only a single rule sends parts to
+///
+/// Workflows no longer reached from `start` are left out
+pub fn optimise(system: &SortingSystem, start: &str) -> SortingSystem {
+    let mut rules: Rules = system.workflows().map(|w| (w.key(), w.rules().to_vec())).collect();
+    loop {
+        let before = rules.clone();
+        for wf in rules.values_mut() {
+            *wf = merge_adjacent(drop_redundant(std::mem::take(wf)));
+        }
+        skip_redirects(&mut rules, start);
+        while inline_single_use(&mut rules, start) {}
+        let reached = reachable(&rules, start);
+        rules.retain(|name, _| reached.contains(name));
+
+        if rules == before { break }
+    }
+    rules.into_iter().map(|(name, rules)| Workflow::new(name, rules)).collect()
+}
+
+/// Drops rules no part gets to, and turns a condition met by every part left into an unconditional rule
+fn drop_redundant(rules: Vec<Rule>) -> Vec<Rule> {
+    let mut residual: Vec<Ratings> = vec![ALL_RATINGS];
+    let mut out = vec![];
+    for rule in rules {
+        if residual.is_empty() { break }
+        match rule {
+            Rule::ConAct(c, a) => {
+                let (hit, miss) = c.partition_all(&residual);
+                if hit.is_empty() { continue }
+                out.push(if miss.is_empty() { Rule::Act(a) } else { Rule::ConAct(c, a) });
+                residual = miss;
+            }
+            Rule::Act(a) => {
+                out.push(Rule::Act(a));
+                residual.clear();
+            }
+        }
+    }
+    out
+}
+
+/// `c1:A,c2:A` becomes `c1||c2:A`, while `c:A,A` becomes `A`
+fn merge_adjacent(rules: Vec<Rule>) -> Vec<Rule> {
+    let mut out: Vec<Rule> = vec![];
+    for mut rule in rules {
+        while let Some(Rule::ConAct(c, a)) = out.last() {
+            rule = match rule {
+                Rule::ConAct(next, b) if *a == b => Rule::ConAct(Condition::Or(Box::new(c.clone()), Box::new(next)), b),
+                Rule::Act(b) if *a == b => Rule::Act(b),
+                _ => break,
+            };
+            out.pop();
+        }
+        out.push(rule);
+    }
+    out
+}
+
+/// Where parts sent to `wf` end up when its only rule sends them on unconditionally
+fn redirect(rules: &Rules, wf: &str) -> Option<Action> {
+    match rules.get(wf).map(Vec::as_slice) {
+        Some([Rule::Act(Action::WorkFlow(next))]) if next.as_ref() == wf => None,
+        Some([Rule::Act(action)]) => Some(action.clone()),
+        _ => None,
+    }
+}
+
+/// Points rules sending parts to a workflow such as `px{A}` or `px{qqz}` at `A` or `qqz` instead
+fn skip_redirects(rules: &mut Rules, start: &str) {
+    let mut names = rules.keys().cloned().collect::<Vec<_>>();
+    names.sort();
+    for name in names {
+        let Some(to) = redirect(rules, &name) else { continue };
+        for rule in rules.values_mut().flatten() {
+            let (Rule::ConAct(_, action) | Rule::Act(action)) = rule;
+            if matches!(action, Action::WorkFlow(wf) if *wf == name) { *action = to.clone() }
+        }
+        // parts still start out at `start`
+        if *name != *start { rules.remove(&name); }
+    }
+}
+
+/// Moves the rules of a workflow only one rule sends parts to into the workflow of that rule,
+/// guarding them with the rule's condition; returns whether any workflow was inlined
+fn inline_single_use(rules: &mut Rules, start: &str) -> bool {
+    let mut senders: HashMap<Rc<str>, Vec<(Rc<str>, usize)>> = HashMap::new();
+    for (name, wf) in rules.iter() {
+        for (i, rule) in wf.iter().enumerate() {
+            if let Rule::ConAct(_, Action::WorkFlow(to)) | Rule::Act(Action::WorkFlow(to)) = rule {
+                senders.entry(to.clone()).or_default().push((name.clone(), i));
+            }
+        }
+    }
+    let mut candidates = senders.into_iter()
+        .filter_map(|(wf, from)| match from.as_slice() {
+            [(sender, index)] if *wf != *start && *sender != wf => Some((wf, sender.clone(), *index)),
+            _ => None,
+        })
+        // parts failing every rule of the inlined workflow would go on to the sender's next rule
+        .filter(|(wf, ..)| matches!(rules.get(wf).and_then(|r| r.last()), Some(Rule::Act(_))))
+        .collect::<Vec<_>>();
+    candidates.sort();
+
+    let Some((wf, sender, index)) = candidates.into_iter().next() else { return false };
+    let inlined = rules.remove(&wf).expect("inlined workflow is defined");
+    let sender = rules.get_mut(&sender).expect("sending workflow is defined");
+    let tail = sender.split_off(index);
+    match &tail[0] {
+        // the unconditional rule is last, nothing after it ever applies
+        Rule::Act(_) => sender.extend(inlined),
+        Rule::ConAct(c, _) => {
+            sender.extend(inlined.into_iter().map(|rule| match rule {
+                Rule::ConAct(d, a) => Rule::ConAct(Condition::And(Box::new(c.clone()), Box::new(d)), a),
+                Rule::Act(a) => Rule::ConAct(c.clone(), a),
+            }));
+            sender.extend(tail.into_iter().skip(1));
+        }
+    }
+    true
+}
+
+fn reachable(rules: &Rules, start: &str) -> HashSet<Rc<str>> {
+    let mut reached = HashSet::new();
+    let mut queue = rules.get_key_value(start).map(|(name, _)| name.clone()).into_iter().collect::<Vec<_>>();
+    while let Some(name) = queue.pop() {
+        if !reached.insert(name.clone()) { continue }
+        queue.extend(
+            rules[&name].iter()
+                .filter_map(|rule| match rule {
+                    Rule::ConAct(_, Action::WorkFlow(to)) | Rule::Act(Action::WorkFlow(to)) => rules.get_key_value(to),
+                    _ => None,
+                })
+                .map(|(name, _)| name.clone())
+        );
+    }
+    reached
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::day19::{parse_puzzle_data, part::{Part, Unit}};
+
+    // parts spread across the ratings, landing on either side of most conditions
+    fn spread() -> impl Iterator<Item = Part> {
+        let r = || (1..4001).step_by(191);
+        r().flat_map(move |x| r().flat_map(move |m| r().flat_map(move |a| r().map(move |s| Part { x, m, a, s }))))
+    }
+
+    fn assert_same(before: &SortingSystem, after: &SortingSystem, parts: &[Part]) {
+        assert_eq!(before.total_combinations("in", &ALL_RATINGS), after.total_combinations("in", &ALL_RATINGS));
+        for &part in parts.iter().chain(&spread().collect::<Vec<_>>()) {
+            assert_eq!(before.process_part(part, "in"), after.process_part(part, "in"), "{part:?}");
+        }
+    }
+
+    #[test]
+    fn test_optimise_samples() {
+        for file in ["src/bin/day19/sample.txt", "src/bin/day19/sample1.txt"] {
+            let (parts, system) = parse_puzzle_data(file);
+            let optimised = optimise(&system, "in");
+            assert_same(&system, &optimised, &parts);
+
+            // printed back in the syntax it's parsed from
+            let reparsed = optimised.to_string().parse::<SortingSystem>().expect("Ops");
+            assert_eq!(reparsed.to_string(), optimised.to_string());
+            assert_same(&system, &reparsed, &parts);
+        }
+    }
+
+    #[test]
+    fn test_optimise() {
+        let system = "in{x>10:one,m<5:two,x>20:R,three}\n\
+            one{a>5:two,s<=3:R,R}\n\
+            two{x<100:A,x<50:R,A}\n\
+            three{m>=5:four,A}\n\
+            four{x<5:R,x>=5:R}\n\
+            lost{A}"
+            .parse::<SortingSystem>()
+            .expect("Ops");
+        let optimised = optimise(&system, "in");
+        assert_same(&system, &optimised, &[]);
+
+        // `two` always accepts, `one` rejects whatever it doesn't send to `two`, and so does `three` through `four`
+        assert_eq!(optimised.to_string(), "in{x>10&&a>5:A,x>10:R,m<5:A,R}");
+        let accepted: Unit = 3990 * 4000 * 3995 * 4000 + 10 * 4 * 4000 * 4000;
+        assert_eq!(optimised.total_combinations("in", &ALL_RATINGS), accepted);
+    }
+
+    #[test]
+    fn test_merge_and_drop() {
+        let rules = |s: &str| s.split(',').map(|r| r.parse::<Rule>().expect("Ops")).collect::<Vec<_>>();
+        let show = |r: Vec<Rule>| r.iter().map(|r| format!("{r:?}")).collect::<Vec<_>>().join(",");
+
+        assert_eq!(show(merge_adjacent(rules("x>10:A,m<5:A,s>3:R,a<2:one,a>3:A,A"))), "x>10||m<5:A,s>3:R,a<2:one,A");
+        assert_eq!(show(drop_redundant(rules("x>10:A,x>20:R,x<=10:one,A"))), "x>10:A,one");
+        assert_eq!(show(drop_redundant(rules("x>0:A,R"))), "A");
+    }
+}
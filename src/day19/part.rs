@@ -1,9 +1,14 @@
-use std::{fmt::Debug, num::ParseIntError, str::FromStr};
+use std::{fmt::Debug, num::ParseIntError, ops::Range, str::FromStr};
+use crate::day19::rule::PartVar;
 
-pub(crate) type Unit = usize;
+pub type Unit = usize;
+/// A box of parts, as a range of ratings per category in `x`, `m`, `a`, `s` order
+pub type Ratings = [Range<Unit>; 4];
+/// Every part there can be, rated from 1 to 4000 in each category
+pub const ALL_RATINGS: Ratings = [1..4001, 1..4001, 1..4001, 1..4001];
 
 #[derive(Clone, Copy)]
-pub(crate) struct Part {
+pub struct Part {
     // each part is rated in each of four categories
     pub x: Unit, // x: Extremely cool looking
     pub m: Unit, // m: Musical (it makes a noise when you hit it)
@@ -20,9 +25,17 @@ impl Debug for Part {
 }
 
 impl Part {
-    pub(crate) fn sum(&self) -> Unit {
+    pub fn sum(&self) -> Unit {
         self.x + self.m + self.a + self.s
     }
+    pub fn get(&self, var: PartVar) -> Unit {
+        match var {
+            PartVar::X => self.x,
+            PartVar::M => self.m,
+            PartVar::A => self.a,
+            PartVar::S => self.s,
+        }
+    }
 }
 
 impl FromStr for Part {
@@ -0,0 +1,430 @@
+use crate::day19::{part::{Part, Ratings, Unit}, error::{ErrorKind, ParseError}};
+use std::{fmt::{Debug, Display}, ops::Range, rc::Rc, str::FromStr};
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum PartVar { X = 0, M, A, S }
+
+impl Debug for PartVar {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PartVar::X => write!(f, "x"),
+            PartVar::M => write!(f, "m"),
+            PartVar::A => write!(f, "a"),
+            PartVar::S => write!(f, "s"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Operand { GT, LT, GE, LE, EQ, NE }
+
+impl Operand {
+    // longer tokens first, so that `<=` isn't taken for `<`
+    const TOKENS: [(&'static str, Operand); 6] = [
+        ("<=", Operand::LE), (">=", Operand::GE), ("==", Operand::EQ), ("!=", Operand::NE), ("<", Operand::LT), (">", Operand::GT)
+    ];
+
+    pub fn holds(&self, rating: Unit, value: Unit) -> bool {
+        match self {
+            Operand::GT => rating > value,
+            Operand::LT => rating < value,
+            Operand::GE => rating >= value,
+            Operand::LE => rating <= value,
+            Operand::EQ => rating == value,
+            Operand::NE => rating != value,
+        }
+    }
+    /// Splits `rng` into the ratings compared true against `value` and those compared false
+    fn split(&self, value: Unit, rng: &Range<Unit>) -> (Vec<Range<Unit>>, Vec<Range<Unit>>) {
+        // ratings below, at and above the value
+        let (at, above) = (value.clamp(rng.start, rng.end), value.saturating_add(1).clamp(rng.start, rng.end));
+        let (below, equal, after) = (rng.start..at, at..above, above..rng.end);
+        let (up_to, from) = (rng.start..above, at..rng.end);
+        match self {
+            Operand::GT => (vec![after], vec![up_to]),
+            Operand::LT => (vec![below], vec![from]),
+            Operand::GE => (vec![from], vec![below]),
+            Operand::LE => (vec![up_to], vec![after]),
+            Operand::EQ => (vec![equal], vec![below, after]),
+            Operand::NE => (vec![below, after], vec![equal]),
+        }
+    }
+}
+
+impl Debug for Operand {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (token, _) = Operand::TOKENS.iter().find(|(_, op)| op == self).expect("every operand has a token");
+        write!(f, "{token}")
+    }
+}
+
+/// A comparison of a part's rating against a value, or comparisons combined with `&&` and `||`
+#[derive(Clone, PartialEq, Eq)]
+pub enum Condition {
+    Compare(PartVar, Operand, Unit),
+    And(Box<Condition>, Box<Condition>),
+    Or(Box<Condition>, Box<Condition>),
+}
+
+impl Condition {
+    pub fn validate(&self, part: Part) -> bool {
+        match self {
+            Condition::Compare(var, operand, value) => operand.holds(part.get(*var), *value),
+            Condition::And(a, b) => a.validate(part) && b.validate(part),
+            Condition::Or(a, b) => a.validate(part) || b.validate(part),
+        }
+    }
+    /// Why the condition holds for `part` or not, e.g. `s=2876 is not <1351`
+    pub fn explain(&self, part: Part) -> String {
+        match self {
+            Condition::Compare(var, operand, value) => {
+                let not = if self.validate(part) { "" } else { "not " };
+                format!("{var:?}={} is {not}{operand:?}{value}", part.get(*var))
+            }
+            Condition::And(a, b) => format!("{} and {}", a.explain(part), b.explain(part)),
+            Condition::Or(a, b) => format!("{} or {}", a.explain(part), b.explain(part)),
+        }
+    }
+    /// Splits the `rngs` box of ratings into the boxes meeting the condition and those left over;
+    /// all boxes returned are disjoint and none is empty
+    pub fn partition(&self, rngs: &Ratings) -> (Vec<Ratings>, Vec<Ratings>) {
+        match self {
+            Condition::Compare(var, operand, value) => {
+                let (hit, miss) = operand.split(*value, &rngs[*var as usize]);
+                let boxes = |pieces: Vec<Range<Unit>>| pieces
+                    .into_iter()
+                    .map(|r| {
+                        let mut b = rngs.clone();
+                        b[*var as usize] = r;
+                        b
+                    })
+                    .filter(|b| b.iter().all(|r| !r.is_empty()))
+                    .collect::<Vec<_>>();
+                (boxes(hit), boxes(miss))
+            }
+            // parts meeting `a` are further split by `b`
+            Condition::And(a, b) => {
+                let (hit, mut miss) = a.partition(rngs);
+                let (hit, rest) = Condition::partition_all(b, &hit);
+                miss.extend(rest);
+                (hit, miss)
+            }
+            // parts failing `a` get another chance with `b`
+            Condition::Or(a, b) => {
+                let (mut hit, miss) = a.partition(rngs);
+                let (more, miss) = Condition::partition_all(b, &miss);
+                hit.extend(more);
+                (hit, miss)
+            }
+        }
+    }
+    /// Same as [`Condition::partition`] over many boxes at once
+    pub fn partition_all(&self, boxes: &[Ratings]) -> (Vec<Ratings>, Vec<Ratings>) {
+        boxes.iter()
+            .map(|b| self.partition(b))
+            .fold((vec![], vec![]), |(mut hit, mut miss), (h, m)| {
+                hit.extend(h);
+                miss.extend(m);
+                (hit, miss)
+            })
+    }
+}
+
+/// Recursive descent over `||`, binding looser than `&&`, binding looser than brackets and single comparisons
+struct ConditionParser<'a> {
+    s: &'a str,
+    pos: usize,
+}
+
+impl ConditionParser<'_> {
+    fn rest(&self) -> &str {
+        &self.s[self.pos..]
+    }
+    fn eat(&mut self, token: &str) -> bool {
+        let found = self.rest().starts_with(token);
+        if found { self.pos += token.len() }
+        found
+    }
+    fn next_char(&self) -> &str {
+        let rest = self.rest();
+        &rest[..rest.chars().next().map_or(0, char::len_utf8)]
+    }
+    fn error(&self, kind: ErrorKind, text: &str) -> ParseError {
+        ParseError::new(kind, self.pos + 1, text)
+    }
+    fn any(&mut self) -> Result<Condition, ParseError> {
+        let mut c = self.all()?;
+        while self.eat("||") {
+            c = Condition::Or(Box::new(c), Box::new(self.all()?));
+        }
+        Ok(c)
+    }
+    fn all(&mut self) -> Result<Condition, ParseError> {
+        let mut c = self.term()?;
+        while self.eat("&&") {
+            c = Condition::And(Box::new(c), Box::new(self.term()?));
+        }
+        Ok(c)
+    }
+    fn term(&mut self) -> Result<Condition, ParseError> {
+        if !self.eat("(") { return self.compare() }
+        let c = self.any()?;
+        if !self.eat(")") { return Err(self.error(ErrorKind::Condition, self.rest())) }
+        Ok(c)
+    }
+    fn compare(&mut self) -> Result<Condition, ParseError> {
+        // x>10
+        let text = self.next_char();
+        let var = match text {
+            "x" => PartVar::X,
+            "m" => PartVar::M,
+            "a" => PartVar::A,
+            "s" => PartVar::S,
+            _ => return Err(self.error(ErrorKind::Category, text)),
+        };
+        self.pos += 1;
+        let Some(operand) = Operand::TOKENS.iter().find(|(t, _)| self.eat(t)).map(|&(_, op)| op) else {
+            return Err(self.error(ErrorKind::Operand, self.next_char()))
+        };
+        // the rating runs up to whatever combines or closes the comparison
+        let len = self.rest().find(['&', '|', ')']).unwrap_or(self.rest().len());
+        let value = &self.rest()[..len];
+        let value = Unit::from_str(value).map_err(|_| self.error(ErrorKind::Rating, value))?;
+        self.pos += len;
+        Ok(Condition::Compare(var, operand, value))
+    }
+}
+
+impl FromStr for Condition {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // x>10, m<=20&&a!=5, (x>10||m<20)&&s==3
+        let mut parser = ConditionParser { s, pos: 0 };
+        let c = parser.any()?;
+        if !parser.rest().is_empty() { return Err(parser.error(ErrorKind::Condition, parser.rest())) }
+        Ok(c)
+    }
+}
+
+impl Debug for Condition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Condition::Compare(var, operand, value) => write!(f, "{var:?}{operand:?}{value}"),
+            // `&&` binds tighter than `||`, so only an `||` within an `&&` needs brackets
+            Condition::And(a, b) => {
+                for (i, c) in [a, b].into_iter().enumerate() {
+                    if i > 0 { write!(f, "&&")? }
+                    match c.as_ref() {
+                        Condition::Or(..) => write!(f, "({c:?})")?,
+                        _ => write!(f, "{c:?}")?,
+                    }
+                }
+                Ok(())
+            }
+            Condition::Or(a, b) => write!(f, "{a:?}||{b:?}"),
+        }
+    }
+}
+impl Display for Condition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        <Self as Debug>::fmt(self, f)
+    }
+}
+
+#[derive(Clone, PartialEq, Eq)]
+pub enum Action {
+    WorkFlow(Rc<str>),
+    Accept,
+    Reject,
+}
+
+impl FromStr for Action {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "A" => Self::Accept,
+            "R" => Self::Reject,
+            wf if !wf.is_empty() && wf.chars().all(|c| c.is_ascii_lowercase()) => Self::WorkFlow(wf.into()),
+            wf => return Err(ParseError::new(ErrorKind::Name, 1, wf)),
+        })
+    }
+}
+
+impl Debug for Action {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Action::WorkFlow(s) => write!(f, "{}", s),
+            Action::Accept => write!(f, "A"),
+            Action::Reject => write!(f, "R"),
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Eq)]
+pub enum Rule {
+    // each rule specifies a condition and where to send the part if the condition is true
+    // The last rule in each workflow has no condition and always applies if reached.
+    ConAct(Condition, Action),
+    Act(Action),
+}
+
+impl Rule {
+    pub fn validate(&self, part: Part) -> Option<Action> {
+        match self {
+            Rule::ConAct(c, a) if c.validate(part) => Some(a.clone()),
+            Rule::Act(a) => Some(a.clone()),
+            _ => None
+        }
+    }
+}
+
+impl FromStr for Rule {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // x>10:one, m<20:two, a>30:R, A
+        let mut split = s.split(':');
+        let o = match (split.next(), split.next(), split.next()) {
+            (Some(s), None, _) => Self::Act(s.parse::<Action>()?),
+            (Some(op), Some(res), None) => Self::ConAct(
+                op.parse::<Condition>()?,
+                res.parse::<Action>()
+                    .map_err(|e| e.shift(0, op.len() + 1))?,
+            ),
+            _ => return Err(ParseError::new(ErrorKind::Rule, 1, s)),
+        };
+        Ok(o)
+    }
+}
+
+impl Debug for Rule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Rule::ConAct(c, r) => write!(f, "{:?}:{:?}", c, r),
+            Rule::Act(r) => write!(f, "{:?}", r),
+        }
+    }
+}
+
+impl Display for Rule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        <Self as Debug>::fmt(self, f)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::day19::workflow::Workflow;
+
+    #[test]
+    fn test_rule_validate() {
+        let mut res = [
+            Some(Action::WorkFlow("one".into())),
+            None,
+            Some(Action::WorkFlow("two".into())),
+            None,
+            Some(Action::Reject),
+            None,
+            Some(Action::Accept),
+        ]
+        .into_iter();
+        let wf = "ex{x>10:one,x<10:one,m<20:two,m>20:two,a<30:R,a>30:R,A}"
+            .parse::<Workflow>()
+            .expect("Ops");
+        let part = Part {
+            x: 11,
+            m: 0,
+            a: 20,
+            s: 0,
+        };
+
+        wf.iter().for_each(|rule| {
+            println!("{:?} => {:?} = {:?}", rule, part, rule.validate(part));
+            assert_eq!(
+                format!("{:?}", res.next().unwrap()),
+                format!("{:?}", rule.validate(part))
+            );
+        });
+    }
+
+    #[test]
+    fn test_rule_parse() {
+        let inp = "x>10:one\nm<20:two\na>30:R\nA";
+        inp.lines().for_each(|s| {
+            let r = s.parse::<Rule>().expect("Rule::parse() error!");
+            println!("{:?}", r);
+            assert_eq!(&format!("{:?}", r), &s)
+        })
+    }
+
+    #[test]
+    fn test_condition_parse() {
+        for inp in ["x<=10", "m>=20", "a==30", "s!=40", "x>10&&m<20", "x>10||m<20&&a!=5", "(x>10||m<20)&&a!=5"] {
+            let c = inp.parse::<Condition>().expect("Condition::parse() error!");
+            assert_eq!(format!("{c:?}"), inp);
+        }
+        // redundant brackets are dropped
+        assert_eq!(format!("{:?}", "(x>10)||(m<20&&s==1)".parse::<Condition>().expect("Ops")), "x>10||m<20&&s==1");
+
+        let data = [
+            ("x=10", (ErrorKind::Operand, 2, "=")),
+            ("x>10&&", (ErrorKind::Category, 7, "")),
+            ("x>10&m<20", (ErrorKind::Condition, 5, "&m<20")),
+            ("(x>10||m<20", (ErrorKind::Condition, 12, "")),
+            ("x>10)", (ErrorKind::Condition, 5, ")")),
+            ("x>=1o", (ErrorKind::Rating, 4, "1o")),
+        ];
+        for (inp, (kind, column, text)) in data {
+            let err = inp.parse::<Condition>().expect_err("should fail to parse");
+            assert_eq!(err, ParseError { kind, line: 1, column, text: text.into() }, "{inp}");
+        }
+    }
+
+    #[test]
+    fn test_condition_partition() {
+        let rngs = [3..9, 1..7, 2..5, 1..3];
+        for inp in ["x<=5", "m>=4", "a==3", "s!=2", "x>4&&m<3", "x==9||a!=3", "(x>5||m<2)&&(a>=4||s==1)", "x>20", "x<20||m<1"] {
+            let c = inp.parse::<Condition>().expect("Ops");
+            let (hit, miss) = c.partition(&rngs);
+
+            // every part of the box falls in exactly one of the boxes, on the side its condition decides
+            for x in rngs[0].clone() { for m in rngs[1].clone() { for a in rngs[2].clone() { for s in rngs[3].clone() {
+                let inside = |b: &Ratings| [x, m, a, s].iter().zip(b).all(|(v, r)| r.contains(v));
+                let part = Part { x, m, a, s };
+                let (h, m) = (hit.iter().filter(|b| inside(b)).count(), miss.iter().filter(|b| inside(b)).count());
+                assert_eq!((h, m), if c.validate(part) { (1, 0) } else { (0, 1) }, "{inp} {part:?}");
+            }}}}
+            assert!(hit.iter().chain(&miss).all(|b| b.iter().all(|r| !r.is_empty())), "{inp}");
+        }
+    }
+
+    #[test]
+    fn test_condition_partition_max_value() {
+        // rules can compare against the largest value, one past the last rating a range can hold
+        let max = Unit::MAX;
+        let rngs = [max - 3..max, 1..4001, 1..4001, 1..4001];
+        let x = |boxes: Vec<Ratings>| boxes.iter().map(|b| (b[0].start, b[0].end)).collect::<Vec<_>>();
+        let all = (max - 3, max);
+        for (inp, hit, miss) in [
+            ("x==18446744073709551615", vec![], vec![all]),
+            ("x!=18446744073709551615", vec![all], vec![]),
+            ("x<18446744073709551615", vec![all], vec![]),
+            ("x>=18446744073709551614", vec![(max - 1, max)], vec![(max - 3, max - 1)]),
+            ("x<=18446744073709551613", vec![(max - 3, max - 1)], vec![(max - 1, max)]),
+        ] {
+            let (h, m) = inp.parse::<Condition>().expect("Ops").partition(&rngs);
+            assert_eq!((x(h), x(m)), (hit, miss), "{inp}");
+        }
+    }
+
+    #[test]
+    fn test_condition_explain() {
+        let part = Part { x: 11, m: 0, a: 20, s: 0 };
+        let c = "x>=11&&a!=20".parse::<Condition>().expect("Ops");
+        assert!(!c.validate(part));
+        assert_eq!(c.explain(part), "x=11 is >=11 and a=20 is not !=20");
+    }
+}
@@ -0,0 +1,298 @@
+use crate::day19::part::Unit;
+use crate::day19::workflow::Workflow;
+use crate::day19::error::ParseError;
+use crate::day19::{
+    part::Part,
+    rule::{Action, Rule},
+    tree::Origin,
+};
+use std::ops::Range;
+use std::{collections::{HashMap, HashSet}, fmt::Display, rc::Rc, str::FromStr};
+
+/// A box of part ratings, per category, sharing the same fate along with the rules applied to them on the way
+#[derive(Debug, Clone, PartialEq)]
+pub struct Region {
+    pub ranges: [Range<Unit>; 4],
+    pub action: Action,
+    pub path: Vec<Origin>,
+}
+
+impl Region {
+    pub fn combinations(&self) -> Unit {
+        self.ranges.iter().map(|r| r.len() as Unit).product()
+    }
+}
+
+pub struct SortingSystem {
+    map: HashMap<Rc<str>, Workflow>
+}
+
+impl SortingSystem {
+    pub fn get(&self, workflow: &str) -> Option<&Workflow> {
+        self.map.get(workflow)
+    }
+    /// All workflows, in no particular order
+    pub fn workflows(&self) -> impl Iterator<Item = &Workflow> {
+        self.map.values()
+    }
+    pub fn process_part(&self, part: Part, workflow: &str) -> Option<Action> {
+        // If a part is sent to another workflow, it immediately switches to the start of that workflow instead and never returns.
+        // If a part is accepted (sent to A) or rejected (sent to R), the part immediately stops any further processing.
+        let mut wf = self
+            .map
+            .get(workflow)
+            .expect("SortingSystem::process() - Starting workflow unknown!!");
+
+        while let Some(Action::WorkFlow(next)) = wf.validate(part) {
+            wf = self
+                .map
+                .get(&next)
+                .expect("SortingSystem::process() - redirected to non-existent Workflow");
+        }
+        wf.validate(part)
+    }
+
+    pub fn total_combinations(&self, wf: &str, rngs: &[Range<Unit>; 4]) -> Unit {
+        // boxes of ratings no rule has taken yet
+        let mut residual = vec![rngs.clone()];
+
+        self.map
+            .get(wf)
+            .expect("System::total_combinations - Workflow name doesn't exist")
+            .iter()
+            .map(|rule| {
+                // Process rule into "Action" & "target" boxes
+                match rule {
+                    // Process Conditional rule into "target" and "remaining" boxes
+                    Rule::ConAct(c, a) => {
+                        // partition every remaining box and update "target" and "remaining" accordingly
+                        let (target, remaining) = c.partition_all(&residual);
+                        residual = remaining;
+                        (a, target)
+                    },
+                    // Pass-through action with whatever boxes are left
+                    Rule::Act(a) => (a, std::mem::take(&mut residual)),
+                }
+            })
+            .map(|(a,target)|{
+                // Process Action given "target" boxes
+                match a {
+                    Action::WorkFlow(next_wf) => target
+                        .iter()
+                        .map(|t| self.total_combinations(next_wf, t))
+                        .sum::<Unit>(),
+                    Action::Accept => target
+                        .iter()
+                        .map(|t| t.iter().map(|r| r.len() as Unit).product::<Unit>())
+                        .sum::<Unit>(),
+                    Action::Reject => 0,
+                }
+            })
+            .sum::<Unit>()
+    }
+
+    /// Splits the `rngs` box into the disjoint boxes of parts accepted or rejected, each with the rules that sent them there
+    pub fn regions(&self, wf: &str, rngs: &[Range<Unit>; 4]) -> Vec<Region> {
+        let mut regions = vec![];
+        self.collect_regions(wf, rngs.clone(), &mut vec![], &mut regions);
+        regions
+    }
+    fn collect_regions(&self, wf: &str, rngs: [Range<Unit>; 4], path: &mut Vec<Origin>, regions: &mut Vec<Region>) {
+        let workflow = self.map
+            .get(wf)
+            .expect("System::regions - Workflow name doesn't exist");
+
+        let mut residual = vec![rngs];
+        for (index, rule) in workflow.iter().enumerate() {
+            if residual.is_empty() { break }
+            let (target, action) = match rule {
+                Rule::ConAct(c, a) => {
+                    let (target, remaining) = c.partition_all(&residual);
+                    residual = remaining;
+                    (target, a)
+                },
+                // nothing is left for any rule after an unconditional one
+                Rule::Act(a) => (std::mem::take(&mut residual), a),
+            };
+            if target.is_empty() { continue }
+
+            path.push(Origin { workflow: workflow.key(), index, rule: format!("{rule:?}").into() });
+            for ranges in target {
+                match action {
+                    Action::WorkFlow(next_wf) => self.collect_regions(next_wf, ranges, path, regions),
+                    _ => regions.push(Region { ranges, action: action.clone(), path: path.clone() }),
+                }
+            }
+            path.pop();
+        }
+    }
+    /// Rules, as their workflow and position, that no part within `rngs` ever goes through
+    pub fn unused_rules(&self, wf: &str, rngs: &[Range<Unit>; 4]) -> Vec<(Rc<str>, usize)> {
+        let used = self.regions(wf, rngs)
+            .into_iter()
+            .flat_map(|r| r.path)
+            .map(|o| (o.workflow, o.index))
+            .collect::<HashSet<_>>();
+        let mut unused = self.map
+            .values()
+            .flat_map(|w| (0..w.rules().len()).map(|i| (w.key(), i)))
+            .filter(|r| !used.contains(r))
+            .collect::<Vec<_>>();
+        unused.sort();
+        unused
+    }
+}
+
+impl FromStr for SortingSystem {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut map: HashMap<Rc<str>, Workflow> = HashMap::new();
+
+        for (idx, line) in s.lines().enumerate() {
+            let wf = line.parse::<Workflow>().map_err(|e| e.shift(idx, 0))?;
+            map.insert(wf.key(), wf);
+        }
+        Ok(SortingSystem { map })
+    }
+}
+
+impl FromIterator<Workflow> for SortingSystem {
+    fn from_iter<T: IntoIterator<Item = Workflow>>(iter: T) -> Self {
+        SortingSystem { map: iter.into_iter().map(|wf| (wf.key(), wf)).collect() }
+    }
+}
+
+impl Display for SortingSystem {
+    /// One workflow per line, ordered by name, as they're parsed
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut workflows = self.map.values().collect::<Vec<_>>();
+        workflows.sort_by_key(|w| w.key());
+        for (i, wf) in workflows.into_iter().enumerate() {
+            if i > 0 { writeln!(f)? }
+            write!(f, "{wf}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::day19::parse_puzzle_data;
+
+    #[test]
+    fn test_sortingsystem_combinations() {
+        let (_, wfs) = parse_puzzle_data("src/bin/day19/sample.txt");
+        let sum = wfs.total_combinations("in", &[1..4001, 1..4001, 1..4001, 1..4001]);
+        println!("Total combinations: {sum}");
+        // assert_eq!(sum,132_753_196_000_000);
+        assert_eq!(sum,167_409_079_868_000);
+    }
+
+    #[test]
+    fn test_sortingsystem_combinations_compound() {
+        let wfs = "in{x<=3&&m!=2:one,a==4||s>=5:A,two}\none{(x==1||a<3)&&s!=1:R,m>=4:A,two}\ntwo{x!=2&&(m<=1||a>4):A,R}"
+            .parse::<SortingSystem>()
+            .expect("Ops");
+        let rngs = [1..7, 1..7, 1..7, 1..7];
+
+        // against sorting every one of the 6^4 parts
+        let mut accepted = 0;
+        for x in 1..7 { for m in 1..7 { for a in 1..7 { for s in 1..7 {
+            if wfs.process_part(Part { x, m, a, s }, "in") == Some(Action::Accept) { accepted += 1 }
+        }}}}
+        assert_eq!(wfs.total_combinations("in", &rngs), accepted);
+
+        let regions = wfs.regions("in", &rngs);
+        assert_eq!(regions.iter().map(|r| r.combinations()).sum::<Unit>(), 6usize.pow(4));
+        assert_eq!(regions.iter().filter(|r| r.action == Action::Accept).map(|r| r.combinations()).sum::<Unit>(), accepted);
+    }
+
+    #[test]
+    fn test_sortingsystem_regions() {
+        let all = [1..4001, 1..4001, 1..4001, 1..4001];
+        for file in ["src/bin/day19/sample.txt", "src/bin/day19/sample1.txt"] {
+            let (_, wfs) = parse_puzzle_data(file);
+            let regions = wfs.regions("in", &all);
+
+            let accepted = regions.iter().filter(|r| r.action == Action::Accept).map(|r| r.combinations()).sum::<Unit>();
+            assert_eq!(accepted, wfs.total_combinations("in", &all));
+            assert_eq!(regions.iter().map(|r| r.combinations()).sum::<Unit>(), 4000usize.pow(4));
+            // boxes don't overlap, and the rules on each box's path lead from `in` to its action
+            let overlap = |a: &Region, b: &Region| a.ranges.iter().zip(&b.ranges).all(|(a, b)| a.start < b.end && b.start < a.end);
+            for (i, a) in regions.iter().enumerate() {
+                assert!(regions[i+1..].iter().all(|b| !overlap(a, b)));
+                assert_eq!(a.path[0].workflow.as_ref(), "in");
+                assert!(a.path.last().unwrap().rule.ends_with(&format!("{:?}", a.action)));
+            }
+        }
+
+        let (_, wfs) = parse_puzzle_data("src/bin/day19/sample.txt");
+        let regions = wfs.regions("in", &all);
+        let region = regions.iter().find(|r| r.path.iter().any(|o| o.rule.as_ref() == "a>1716:R")).expect("Ops");
+        assert_eq!(region.ranges, [1..4001, 1..839, 1717..4001, 1351..2771]);
+        assert_eq!(region.path.iter().map(|o| o.rule.as_ref()).collect::<Vec<_>>(), ["qqz", "m<1801:hdj", "pv", "a>1716:R"]);
+    }
+
+    #[test]
+    fn test_sortingsystem_unused_rules() {
+        let all = [1..4001, 1..4001, 1..4001, 1..4001];
+        let (_, wfs) = parse_puzzle_data("src/bin/day19/sample.txt");
+        assert!(wfs.unused_rules("in", &all).is_empty());
+
+        // x>20 can't hold for parts with x<=10, nor can anything follow an unconditional rule
+        let wfs = "in{x<11:one,A}\none{x>20:R,m<5:R,A,R}".parse::<SortingSystem>().expect("Ops");
+        assert_eq!(wfs.unused_rules("in", &all), [("one".into(), 0), ("one".into(), 3)]);
+    }
+
+    #[test]
+    fn test_sortingsystem_process() {
+        let (part, wfs) = parse_puzzle_data("src/bin/day19/sample.txt");
+
+        let sum = part
+            .iter()
+            .filter(|&&part| wfs.process_part(part, "in") == Some(Action::Accept))
+            .map(|p| p.sum())
+            .sum::<Unit>();
+
+        assert_eq!(sum, 19114);
+    }
+
+    #[test]
+    fn test_sortingsystem_parse_errors() {
+        use crate::day19::error::ErrorKind;
+
+        let data = [
+            ("px{a<2006:qkq,m>2090:A,rfg}\npv{y>1716:R,A}", (ErrorKind::Category, 2, 4, "y")),
+            ("px{a<2006:qkq,m=2090:A,rfg}", (ErrorKind::Operand, 1, 16, "=")),
+            ("px{a<2006:qkq,m>20x0:A,rfg}", (ErrorKind::Rating, 1, 17, "20x0")),
+            ("px{a<2006:qkq,m>2090:,rfg}", (ErrorKind::Name, 1, 22, "")),
+            ("px{a<2006:qkq,m>=2090&x:A,rfg}", (ErrorKind::Condition, 1, 22, "&x")),
+            ("px{a<2006:qkq:A,rfg}", (ErrorKind::Rule, 1, 4, "a<2006:qkq:A")),
+            ("px{a<2006:qkq,rfg", (ErrorKind::Workflow, 1, 1, "px{a<2006:qkq,rfg")),
+            ("P{A}", (ErrorKind::Name, 1, 1, "P")),
+        ];
+        for (inp, (kind, line, column, text)) in data {
+            let err = inp.parse::<SortingSystem>().err().expect("should fail to parse");
+            assert_eq!(err, ParseError { kind, line, column, text: text.into() });
+        }
+    }
+
+    #[test]
+    fn test_sortingsystem_parse() {
+        let inp =
+            std::fs::read_to_string("src/bin/day19/sample.txt").expect("cannot load sample.txt");
+        let wfs = inp.split("\n\n").next().unwrap();
+        let sorting = wfs
+            .parse::<SortingSystem>()
+            .expect("Failed to parse workflow");
+
+        wfs.lines().for_each(|line| {
+            let wf = line.parse::<Workflow>().expect("msg");
+            let found = sorting.map.get(&wf.key());
+            println!("{:?}", found);
+            assert_eq!(format!("{:?}", found), format!("{:?}", Some(wf)));
+        });
+    }
+}
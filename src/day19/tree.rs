@@ -0,0 +1,244 @@
+use crate::day19::{
+    part::Part,
+    rule::{Action, Condition, Rule},
+    system::SortingSystem,
+};
+use std::{collections::{HashMap, HashSet}, fmt::Display, rc::Rc};
+use thiserror::Error;
+
+pub type NodeId = usize;
+
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum CompileError {
+    #[error("Workflow [{0}] isn't defined")]
+    UnknownWorkflow(Rc<str>),
+    #[error("Workflow [{0}] can send parts back to itself")]
+    Cycle(Rc<str>),
+    #[error("Workflow [{0}] has no rule for parts failing every condition")]
+    MissingFallback(Rc<str>),
+}
+
+/// The rule a node was compiled from, as its workflow, its position within the workflow and the rule as written
+#[derive(Debug, Clone, PartialEq)]
+pub struct Origin {
+    pub workflow: Rc<str>,
+    pub index: usize,
+    pub rule: Rc<str>,
+}
+
+#[derive(Debug, Clone)]
+pub enum Node {
+    /// a conditional rule, going to `then` when the condition holds and to the next rule `otherwise`
+    Test { origin: Origin, condition: Condition, then: NodeId, otherwise: NodeId },
+    /// an unconditional rule sending parts on to another workflow
+    Redirect { origin: Origin, to: NodeId },
+    /// parts are either accepted or rejected
+    Leaf { origin: Origin, action: Action },
+}
+
+impl Node {
+    pub fn origin(&self) -> &Origin {
+        match self {
+            Node::Test { origin, .. } | Node::Redirect { origin, .. } | Node::Leaf { origin, .. } => origin,
+        }
+    }
+}
+
+/// All workflows reachable from a starting workflow flattened into a single graph of decisions,
+/// so parts are sorted without looking workflows up by name
+///
+/// A workflow sent parts from more than one rule is compiled once and shared
+#[derive(Debug, Clone)]
+pub struct DecisionTree {
+    nodes: Vec<Node>,
+    root: NodeId,
+}
+
+impl DecisionTree {
+    pub fn compile(system: &SortingSystem, start: &str) -> Result<DecisionTree, CompileError> {
+        let mut compiler = Compiler { system, nodes: vec![], compiled: HashMap::new(), entered: HashSet::new() };
+        let root = compiler.workflow(start)?;
+        Ok(DecisionTree { nodes: compiler.nodes, root })
+    }
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+    pub fn root(&self) -> NodeId {
+        self.root
+    }
+    pub fn node(&self, id: NodeId) -> &Node {
+        &self.nodes[id]
+    }
+    /// Whether `part` is accepted or rejected
+    pub fn decide(&self, part: Part) -> Action {
+        let mut id = self.root;
+        loop {
+            match &self.nodes[id] {
+                Node::Test { condition, then, otherwise, .. } => id = if condition.validate(part) { *then } else { *otherwise },
+                Node::Redirect { to, .. } => id = *to,
+                Node::Leaf { action, .. } => return action.clone(),
+            }
+        }
+    }
+    /// Same as [`DecisionTree::decide`], recording every rule visited on the way and why it applied or not
+    pub fn explain(&self, part: Part) -> Trace {
+        let mut steps: Vec<Step> = vec![];
+        let mut id = self.root;
+        loop {
+            let node = &self.nodes[id];
+            let origin = node.origin().clone();
+            match node {
+                Node::Test { condition, then, otherwise, .. } => {
+                    let matched = condition.validate(part);
+                    steps.push(Step { origin, matched, reason: condition.explain(part) });
+                    id = if matched { *then } else { *otherwise };
+                }
+                Node::Redirect { to, .. } => {
+                    steps.push(Step { origin, matched: true, reason: "always".into() });
+                    id = *to;
+                }
+                Node::Leaf { action, .. } => {
+                    // a leaf reached through a condition shares its origin with the step recorded for it
+                    if steps.last().is_none_or(|s| s.origin != origin) {
+                        steps.push(Step { origin, matched: true, reason: "always".into() });
+                    }
+                    return Trace { part, steps, action: action.clone() }
+                }
+            }
+        }
+    }
+}
+
+struct Compiler<'a> {
+    system: &'a SortingSystem,
+    nodes: Vec<Node>,
+    // node compiled for each workflow
+    compiled: HashMap<Rc<str>, NodeId>,
+    // workflows being compiled, i.e. those the current one was reached from
+    entered: HashSet<Rc<str>>,
+}
+
+impl Compiler<'_> {
+    fn push(&mut self, node: Node) -> NodeId {
+        self.nodes.push(node);
+        self.nodes.len() - 1
+    }
+    fn workflow(&mut self, name: &str) -> Result<NodeId, CompileError> {
+        if let Some(&id) = self.compiled.get(name) { return Ok(id) }
+        let wf = self.system.get(name).ok_or_else(|| CompileError::UnknownWorkflow(name.into()))?;
+        let name = wf.key();
+        if !self.entered.insert(name.clone()) { return Err(CompileError::Cycle(name)) }
+
+        let id = self.rules(&name, wf.rules(), 0)?;
+        self.entered.remove(&name);
+        self.compiled.insert(name, id);
+        Ok(id)
+    }
+    /// Compiles the rules of a workflow from `index` onwards
+    fn rules(&mut self, name: &Rc<str>, rules: &[Rule], index: usize) -> Result<NodeId, CompileError> {
+        let rule = rules.get(index).ok_or_else(|| CompileError::MissingFallback(name.clone()))?;
+        let origin = Origin { workflow: name.clone(), index, rule: format!("{rule:?}").into() };
+        Ok(match rule {
+            Rule::ConAct(condition, action) => {
+                let then = self.action(action, origin.clone())?;
+                let otherwise = self.rules(name, rules, index + 1)?;
+                self.push(Node::Test { origin, condition: condition.clone(), then, otherwise })
+            }
+            Rule::Act(Action::WorkFlow(next)) => {
+                let to = self.workflow(next)?;
+                self.push(Node::Redirect { origin, to })
+            }
+            Rule::Act(action) => self.push(Node::Leaf { origin, action: action.clone() }),
+        })
+    }
+    fn action(&mut self, action: &Action, origin: Origin) -> Result<NodeId, CompileError> {
+        match action {
+            Action::WorkFlow(next) => self.workflow(next),
+            _ => Ok(self.push(Node::Leaf { origin, action: action.clone() })),
+        }
+    }
+}
+
+/// A rule visited by a part, with whether it applied and why
+#[derive(Debug, Clone, PartialEq)]
+pub struct Step {
+    pub origin: Origin,
+    pub matched: bool,
+    pub reason: String,
+}
+
+/// The rules a part went through on its way to being accepted or rejected
+#[derive(Debug, Clone)]
+pub struct Trace {
+    pub part: Part,
+    pub steps: Vec<Step>,
+    pub action: Action,
+}
+
+impl Display for Trace {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{:?}", self.part)?;
+        for Step { origin, matched, reason } in &self.steps {
+            writeln!(
+                f, "  {:<5} #{} {:<12} {} ({reason})",
+                origin.workflow, origin.index + 1, origin.rule, if *matched { "applies" } else { "skipped" }
+            )?;
+        }
+        write!(f, "  => {:?}", self.action)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::day19::parse_puzzle_data;
+
+    #[test]
+    fn test_decide() {
+        for file in ["src/bin/day19/sample.txt", "src/bin/day19/sample1.txt"] {
+            let (parts, system) = parse_puzzle_data(file);
+            let tree = DecisionTree::compile(&system, "in").expect("Ops");
+            for &part in parts.iter() {
+                assert_eq!(Some(tree.decide(part)), system.process_part(part, "in"));
+            }
+        }
+    }
+
+    #[test]
+    fn test_explain() {
+        let (parts, system) = parse_puzzle_data("src/bin/day19/sample.txt");
+        let tree = DecisionTree::compile(&system, "in").expect("Ops");
+        // a node per rule of the 11 workflows, plus a leaf for each of the 9 conditions accepting or rejecting parts
+        assert_eq!(tree.len(), 25 + 9);
+
+        let trace = tree.explain(parts[0]);
+        assert_eq!(trace.action, Action::Accept);
+        // in -> qqz -> qs -> lnx -> A
+        assert_eq!(
+            trace.steps.iter().map(|s| (s.origin.rule.as_ref(), s.matched)).collect::<Vec<_>>(),
+            [("s<1351:px", false), ("qqz", true), ("s>2770:qs", true), ("s>3448:A", false), ("lnx", true), ("m>1548:A", true)]
+        );
+        assert_eq!(trace.steps[0].reason, "s=2876 is not <1351");
+        assert_eq!(trace.steps[2].reason, "s=2876 is >2770");
+
+        // in -> px -> rfg -> gd, rejected by the last rule of gd
+        let trace = tree.explain(parts[1]);
+        assert_eq!(trace.action, Action::Reject);
+        assert_eq!(trace.steps.last().map(|s| (s.origin.workflow.as_ref(), s.origin.index)), Some(("gd", 1)));
+        assert!(trace.to_string().ends_with("  => R"));
+    }
+
+    #[test]
+    fn test_compile_errors() {
+        let compile = |s: &str| DecisionTree::compile(&s.parse::<SortingSystem>().expect("Ops"), "in").err();
+
+        assert_eq!(compile("in{x>10:one,A}"), Some(CompileError::UnknownWorkflow("one".into())));
+        assert_eq!(compile("in{x>10:one,A}\none{m<5:in,R}"), Some(CompileError::Cycle("in".into())));
+        assert_eq!(compile("in{x>10:one,A}\none{m<5:R}"), Some(CompileError::MissingFallback("one".into())));
+        // reaching a workflow from two rules is fine
+        assert_eq!(compile("in{x>10:one,one}\none{m<5:A,R}"), None);
+    }
+}
@@ -1,20 +1,26 @@
-use std::{fmt::Debug, rc::Rc, str::FromStr};
-use crate::{part::Part, rule::{Action, Rule}};
+use std::{fmt::{Debug, Display}, rc::Rc, str::FromStr};
+use crate::day19::{part::Part, rule::{Action, Rule}, error::{ErrorKind, ParseError}};
 
-pub(crate) struct Workflow {
+pub struct Workflow {
     // Each workflow has a name and contains a list of rules
     rules: Rc<[Rule]>,
     name: Rc<str>,
 }
 
 impl Workflow {
-    pub(crate) fn key(&self) -> Rc<str> {
+    pub fn new(name: Rc<str>, rules: Vec<Rule>) -> Workflow {
+        Workflow { name, rules: rules.into() }
+    }
+    pub fn key(&self) -> Rc<str> {
         self.name.clone()
     }
-    pub(crate) fn iter(&self) -> impl Iterator<Item = &Rule> {
+    pub fn iter(&self) -> impl Iterator<Item = &Rule> {
         self.rules.iter()
     }
-    pub(crate) fn validate(&self, part: Part) -> Option<Action> {
+    pub fn rules(&self) -> &[Rule] {
+        &self.rules
+    }
+    pub fn validate(&self, part: Part) -> Option<Action> {
         // The first rule that matches the part being considered is applied immediately,
         // and the part moves on to the destination described by the rule
         self.iter()
@@ -24,16 +30,26 @@ impl Workflow {
 }
 
 impl FromStr for Workflow {
-    type Err = ();
+    type Err = ParseError;
 
     fn from_str(inp: &str) -> Result<Self, Self::Err> {
         // ex{ x>10:one ,m<20:two , a>30:R , A }
-        let mut s = inp.split(&['{','}']);
-        let name = s.next().unwrap().into();
-        let rules = s.next().unwrap()
+        let (name, rules) = inp.strip_suffix('}')
+            .and_then(|s| s.split_once('{'))
+            .ok_or_else(|| ParseError::new(ErrorKind::Workflow, 1, inp))?;
+        let Action::WorkFlow(name) = name.parse::<Action>()? else {
+            return Err(ParseError::new(ErrorKind::Name, 1, name))
+        };
+        // column where each rule starts, right after the '{' or ','
+        let mut column = name.len() + 1;
+        let rules = rules
             .split(',')
-            .map(|r| r.parse::<Rule>().expect("Workflow::from_str() invalid rule"))
-            .collect::<Rc<_>>();
+            .map(|r| {
+                let rule = r.parse::<Rule>().map_err(|e| e.shift(0, column));
+                column += r.len() + 1;
+                rule
+            })
+            .collect::<Result<Rc<_>,_>>()?;
         Ok(Self{name,rules})
     }
 }
@@ -54,6 +70,12 @@ impl Debug for Workflow {
     }
 }
 
+impl Display for Workflow {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        <Self as Debug>::fmt(self, f)
+    }
+}
+
 
 #[cfg(test)]
 mod test {
@@ -1,21 +1,21 @@
 use std::cmp::max;
 use std::{str::FromStr, rc::Rc};
-use crate::run::{Run,RunError};
+use crate::day2::run::{Run,RunError};
 
 #[derive(Debug)]
-pub(crate) struct Game {
-    pub(crate) id: u32,
+pub struct Game {
+    pub id: u32,
     runs: Rc<[Run]>,
     max: Run
 }
 
 impl Game {
-    pub(crate) fn is_feasible(&self, run: &Run) -> bool {
+    pub fn is_feasible(&self, run: &Run) -> bool {
         self.runs
             .iter()
             .all(|r| r.is_feasible(run) )
     }
-    pub(crate) fn power(&self) -> u32 {
+    pub fn power(&self) -> u32 {
         self.max.power()
     }
 }
@@ -0,0 +1,84 @@
+pub mod game;
+pub mod run;
+
+use std::rc::Rc;
+use run::Run;
+use game::Game;
+use crate::Solution;
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+    type Input = Rc<[Game]>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Rc<[Game]> {
+        input
+            .lines()
+            .map(|game| game.parse::<Game>()
+                .map_err(|e| panic!("{} -> {:?}",e,game))
+                .unwrap()
+            )
+            .collect::<Rc<_>>()
+    }
+    fn part1(games: &Rc<[Game]>) -> u32 {
+        let rref = Run { red: 12, blue:14, green:13 };
+
+        games.iter()
+            .filter(|game| game.is_feasible(&rref))
+            .map(|game| game.id )
+            .sum::<u32>()
+    }
+    fn part2(games: &Rc<[Game]>) -> u32 {
+        games.iter()
+            .map(|game| game.power() )
+            .sum::<u32>()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    static INPUT : &str =
+        "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green\n\
+        Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue\n\
+        Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red\n\
+        Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red\n\
+        Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green\n";
+
+    #[test]
+    fn test_game_feasible() {
+
+        let gref = Run { red: 12, blue:14, green:13 };
+
+        let sum = INPUT.lines()
+            .map(|game| game.parse::<Game>().expect("Ops!"))
+            .filter(|game| game.is_feasible(&gref) )
+            .map(|game| game.id)
+            .sum::<u32>();
+
+        assert_eq!(8,sum);
+    }
+
+    #[test]
+    fn test_game_power() {
+        let sum = INPUT.lines()
+            .map(|game| game.parse::<Game>().expect("Ops!"))
+            .map(|game| game.power() )
+            .inspect(|n| println!("{n}"))
+            .sum::<u32>();
+
+        assert_eq!(2286,sum);
+    }
+
+    #[test]
+    fn test_parse_input() {
+        let input = "Game 12: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green";
+        let game = input.parse::<Game>().expect("Ops!");
+        println!("{:?} = {}",game, game.power());
+        assert_eq!(game.power(), 48);
+    }
+}
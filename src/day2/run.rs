@@ -2,7 +2,7 @@ use std::{fmt::Display, num::ParseIntError, str::FromStr};
 
 #[derive(Debug,Default,PartialEq)]
 pub struct Run {
-    pub(crate) red: u32, pub(crate) green: u32, pub(crate) blue: u32
+    pub red: u32, pub green: u32, pub blue: u32
 }
 
 impl Run {
@@ -0,0 +1,145 @@
+pub mod pulse;
+pub mod module;
+pub mod network;
+
+use std::rc::Rc;
+use thiserror::Error;
+use module::Kind;
+use network::Network;
+use pulse::Pulse;
+use crate::Solution;
+
+/// Button presses given up after, should some input of the conjunction feeding `rx` never send high
+pub const MAX_PRESSES: usize = 1 << 20;
+
+#[derive(Debug, PartialEq, Error)]
+pub enum RxError {
+    #[error("[{0}] isn't fed by exactly one module")]
+    NoSingleFeeder(Rc<str>),
+    #[error("[{0}] feeding [{1}] isn't a conjunction module")]
+    NotConjunction(Rc<str>, Rc<str>),
+    #[error("Some input of [{0}] never sent a high pulse within {1} presses")]
+    NoCycle(Rc<str>, usize),
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+    type Input = Network;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Network {
+        input.parse::<Network>().expect("Ops!")
+    }
+    fn part1(net: &Network) -> usize {
+        pulses_product(&mut net.clone(), 1000)
+    }
+    fn part2(net: &Network) -> usize {
+        match presses_until_low(&mut net.clone(), "rx", MAX_PRESSES) {
+            Ok(presses) => presses,
+            Err(e) => panic!("{}",e),
+        }
+    }
+}
+
+/// Pushes the button `presses` times and multiplies the number of low pulses sent with the number of high pulses
+fn pulses_product(net: &mut Network, presses: usize) -> usize {
+    let (mut low, mut high) = (0, 0);
+    for _ in 0..presses {
+        net.push_button(|s| match s.pulse {
+            Pulse::Low => low += 1,
+            Pulse::High => high += 1,
+        });
+    }
+    low * high
+}
+
+/// Fewest button presses needed for a single low pulse to reach `target`
+///
+/// `target` is fed by a single conjunction, which sends low only when all of its inputs last sent high.
+/// Each of those inputs sends high on a fixed cycle, hence the answer is the LCM of the cycles' lengths
+pub fn presses_until_low(net: &mut Network, target: &str, max_presses: usize) -> Result<usize, RxError> {
+    let feeder = match net.inputs_of(target).collect::<Vec<_>>()[..] {
+        [feeder] => feeder.clone(),
+        _ => return Err(RxError::NoSingleFeeder(target.into())),
+    };
+    if !matches!(net.get(&feeder).map(|m| &m.kind), Some(Kind::Conjunction(_))) {
+        return Err(RxError::NotConjunction(feeder, target.into()))
+    }
+    let mut cycles = net.inputs_of(&feeder)
+        .map(|input| (input.clone(), None))
+        .collect::<Vec<(_, Option<usize>)>>();
+
+    let mut presses = 0;
+    while cycles.iter().any(|(_, c)| c.is_none()) {
+        if presses == max_presses { return Err(RxError::NoCycle(feeder, max_presses)) }
+        presses += 1;
+        net.push_button(|s| {
+            if s.pulse == Pulse::High && s.to == feeder {
+                cycles.iter_mut()
+                    .filter(|(input, cycle)| cycle.is_none() && *input == s.from)
+                    .for_each(|(_, cycle)| *cycle = Some(presses));
+            }
+        });
+    }
+
+    Ok(cycles.iter()
+        .filter_map(|(_, c)| *c)
+        .fold(1, num::integer::lcm))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_pulses_product() {
+        for (file, product) in [("src/bin/day20/sample.txt", 32_000_000), ("src/bin/day20/sample1.txt", 11_687_500)] {
+            let inp = std::fs::read_to_string(file).expect("Ops!");
+            assert_eq!(Day20::part1(&Day20::parse(&inp)), product);
+        }
+    }
+
+    #[test]
+    fn test_presses_until_low() {
+        let inp = std::fs::read_to_string("src/bin/day20/sample2.txt").expect("Ops!");
+        let mut net = Day20::parse(&inp);
+
+        // push the button until rx receives a low pulse
+        let mut brute = net.clone();
+        let mut presses = 0;
+        let mut low = false;
+        while !low {
+            presses += 1;
+            brute.push_button(|s| low |= s.to.as_ref() == "rx" && s.pulse == Pulse::Low);
+        }
+
+        assert_eq!(presses_until_low(&mut net, "rx", MAX_PRESSES), Ok(presses));
+        assert_eq!(presses, 15);
+    }
+
+    #[test]
+    fn test_presses_until_low_errors() {
+        let presses = |inp: &str, max| presses_until_low(&mut inp.parse::<Network>().expect("Ops!"), "rx", max);
+
+        // the samples have no rx module
+        for file in ["src/bin/day20/sample.txt", "src/bin/day20/sample1.txt"] {
+            let inp = std::fs::read_to_string(file).expect("Ops!");
+            assert_eq!(presses(&inp, MAX_PRESSES), Err(RxError::NoSingleFeeder("rx".into())));
+            let part2 = crate::catch_panic(|| Day20::part2(&Day20::parse(&inp)));
+            assert_eq!(part2, Err("panicked: [rx] isn't fed by exactly one module".into()));
+        }
+        assert_eq!(
+            presses("broadcaster -> a, b\n%a -> rx\n%b -> rx", MAX_PRESSES),
+            Err(RxError::NoSingleFeeder("rx".into()))
+        );
+        assert_eq!(presses("broadcaster -> a\n%a -> rx", MAX_PRESSES), Err(RxError::NotConjunction("a".into(), "rx".into())));
+        // `x` always sends high to `y`, so `y` only ever sends low to `con`
+        assert_eq!(
+            presses("broadcaster -> x, a\n&x -> y\n&y -> con\n%a -> con\n&con -> rx", 1000),
+            Err(RxError::NoCycle("con".into(), 1000))
+        );
+    }
+}
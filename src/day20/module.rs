@@ -0,0 +1,71 @@
+use std::{collections::HashMap, rc::Rc};
+use crate::day20::pulse::Pulse;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Kind {
+    // sends the pulse it receives to all of its destinations
+    Broadcaster,
+    // ignores high pulses; a low pulse flips it on or off
+    FlipFlop(bool),
+    // remembers the last pulse from each input; sends low only when all remembered pulses are high
+    Conjunction(HashMap<Rc<str>, Pulse>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Module {
+    pub kind: Kind,
+    pub targets: Rc<[Rc<str>]>,
+}
+
+impl Module {
+    pub fn new(kind: Kind, targets: Rc<[Rc<str>]>) -> Module {
+        Module { kind, targets }
+    }
+    /// Registers `input` with a conjunction module, which initially remembers a low pulse for it
+    pub fn connect_input(&mut self, input: Rc<str>) {
+        if let Kind::Conjunction(memory) = &mut self.kind {
+            memory.insert(input, Pulse::Low);
+        }
+    }
+    /// Processes a pulse received from `from`, returning the pulse to send to all targets, if any
+    pub fn receive(&mut self, from: &str, pulse: Pulse) -> Option<Pulse> {
+        match (&mut self.kind, pulse) {
+            (Kind::Broadcaster, _) => Some(pulse),
+            (Kind::FlipFlop(_), Pulse::High) => None,
+            (Kind::FlipFlop(on), Pulse::Low) => {
+                *on = !*on;
+                Some(if *on { Pulse::High } else { Pulse::Low })
+            }
+            (Kind::Conjunction(memory), _) => {
+                if let Some(last) = memory.get_mut(from) { *last = pulse }
+                Some(if memory.values().all(|&p| p == Pulse::High) { Pulse::Low } else { Pulse::High })
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_flip_flop() {
+        let mut ff = Module::new(Kind::FlipFlop(false), Rc::new([]));
+
+        assert_eq!(ff.receive("a", Pulse::High), None);
+        assert_eq!(ff.receive("a", Pulse::Low), Some(Pulse::High));
+        assert_eq!(ff.receive("a", Pulse::High), None);
+        assert_eq!(ff.receive("a", Pulse::Low), Some(Pulse::Low));
+    }
+
+    #[test]
+    fn test_conjunction() {
+        let mut con = Module::new(Kind::Conjunction(HashMap::new()), Rc::new([]));
+        con.connect_input("a".into());
+        con.connect_input("b".into());
+
+        assert_eq!(con.receive("a", Pulse::High), Some(Pulse::High));
+        assert_eq!(con.receive("b", Pulse::High), Some(Pulse::Low));
+        assert_eq!(con.receive("a", Pulse::Low), Some(Pulse::High));
+    }
+}
@@ -0,0 +1,131 @@
+use std::{collections::{HashMap, VecDeque}, rc::Rc, str::FromStr};
+use thiserror::Error;
+use crate::day20::{module::{Kind, Module}, pulse::{Pulse, Signal}};
+
+pub const BROADCASTER: &str = "broadcaster";
+
+#[derive(Debug, PartialEq, Error)]
+pub enum NetworkError {
+    #[error("Line {line}: expected `<module> -> <destinations>`, found [{text}]")]
+    Malformed { line: usize, text: String },
+    #[error("Network has no broadcaster module")]
+    MissingBroadcaster,
+}
+
+#[derive(Debug, Clone)]
+pub struct Network {
+    modules: HashMap<Rc<str>, Module>,
+    // queue of pulses in flight, kept to avoid allocating on every button push
+    queue: VecDeque<Signal>,
+}
+
+impl Network {
+    pub fn get(&self, name: &str) -> Option<&Module> {
+        self.modules.get(name)
+    }
+    /// Names of the modules sending pulses to `name`
+    pub fn inputs_of<'a>(&'a self, name: &'a str) -> impl Iterator<Item=&'a Rc<str>> + 'a {
+        self.modules.iter()
+            .filter(move |(_, m)| m.targets.iter().any(|t| t.as_ref() == name))
+            .map(|(n, _)| n)
+    }
+    /// Sends a low pulse to the broadcaster and processes pulses, in the order they were sent,
+    /// until none is left; `probe` sees every pulse, including those sent to untyped modules like `output`
+    pub fn push_button(&mut self, mut probe: impl FnMut(&Signal)) {
+        self.queue.push_back(Signal { from: "button".into(), to: BROADCASTER.into(), pulse: Pulse::Low });
+
+        while let Some(signal) = self.queue.pop_front() {
+            probe(&signal);
+            let Some(module) = self.modules.get_mut(&signal.to) else { continue };
+            if let Some(pulse) = module.receive(&signal.from, signal.pulse) {
+                self.queue.extend(
+                    module.targets.iter().map(|to| Signal { from: signal.to.clone(), to: to.clone(), pulse })
+                );
+            }
+        }
+    }
+}
+
+impl FromStr for Network {
+    type Err = NetworkError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // %a -> inv, con
+        let mut modules = HashMap::new();
+        for (i, line) in s.lines().enumerate() {
+            let malformed = || NetworkError::Malformed { line: i + 1, text: line.into() };
+            let (module, targets) = line.split_once(" -> ").ok_or_else(malformed)?;
+            let (kind, name) = match module.split_at_checked(1).ok_or_else(malformed)? {
+                ("%", name) => (Kind::FlipFlop(false), name),
+                ("&", name) => (Kind::Conjunction(HashMap::new()), name),
+                _ if module == BROADCASTER => (Kind::Broadcaster, module),
+                _ => return Err(malformed()),
+            };
+            let targets = targets.split(", ").map(Rc::from).collect::<Rc<[Rc<str>]>>();
+            if name.is_empty() || targets.iter().any(|t| t.is_empty()) { return Err(malformed()) }
+            // a module declared twice would silently replace the first one
+            if modules.insert(Rc::from(name), Module::new(kind, targets)).is_some() { return Err(malformed()) }
+        }
+
+        if !modules.contains_key(BROADCASTER) { return Err(NetworkError::MissingBroadcaster) }
+
+        // conjunction modules need to know all of their inputs upfront
+        let links = modules.iter()
+            .flat_map(|(name, m)| m.targets.iter().map(move |t| (name.clone(), t.clone())))
+            .collect::<Vec<_>>();
+        for (from, to) in links {
+            if let Some(m) = modules.get_mut(&to) { m.connect_input(from) }
+        }
+
+        Ok(Network { modules, queue: VecDeque::new() })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_push_button() {
+        let inp = std::fs::read_to_string("src/bin/day20/sample1.txt").expect("Ops!");
+        let mut net = inp.parse::<Network>().expect("Ops!");
+
+        let mut signals = vec![];
+        net.push_button(|s| signals.push(format!("{:?}", s)));
+        assert_eq!(
+            signals,
+            [
+                "button -low-> broadcaster", "broadcaster -low-> a", "a -high-> inv", "a -high-> con",
+                "inv -low-> b", "con -high-> output", "b -high-> con", "con -low-> output"
+            ]
+        );
+
+        signals.clear();
+        net.push_button(|s| signals.push(format!("{:?}", s)));
+        assert_eq!(
+            signals,
+            ["button -low-> broadcaster", "broadcaster -low-> a", "a -low-> inv", "a -low-> con", "inv -high-> b", "con -high-> output"]
+        );
+    }
+
+    #[test]
+    fn test_parse_network() {
+        let inp = std::fs::read_to_string("src/bin/day20/sample1.txt").expect("Ops!");
+        let net = inp.parse::<Network>().expect("Ops!");
+
+        let mut inputs = net.inputs_of("con").map(|n| n.as_ref()).collect::<Vec<_>>();
+        inputs.sort();
+        assert_eq!(inputs, ["a", "b"]);
+        assert_eq!(net.get("b").map(|m| m.kind.clone()), Some(Kind::FlipFlop(false)));
+
+        assert_eq!(
+            "broadcaster -> a\n%a => b".parse::<Network>().err(),
+            Some(NetworkError::Malformed { line: 2, text: "%a => b".into() })
+        );
+        assert_eq!(
+            "broadcaster -> a\n%a -> b\n&a -> b".parse::<Network>().err(),
+            Some(NetworkError::Malformed { line: 3, text: "&a -> b".into() })
+        );
+        assert_eq!("%a -> b".parse::<Network>().err(), Some(NetworkError::MissingBroadcaster));
+    }
+}
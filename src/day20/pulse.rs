@@ -0,0 +1,20 @@
+use std::{fmt::Debug, rc::Rc};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Pulse { Low, High }
+
+/// A pulse travelling from one module to another
+#[derive(Clone)]
+pub struct Signal {
+    pub from: Rc<str>,
+    pub to: Rc<str>,
+    pub pulse: Pulse,
+}
+
+impl Debug for Signal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // broadcaster -low-> a
+        let pulse = match self.pulse { Pulse::Low => "low", Pulse::High => "high" };
+        write!(f, "{} -{}-> {}", self.from, pulse, self.to)
+    }
+}
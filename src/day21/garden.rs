@@ -0,0 +1,68 @@
+use std::str::FromStr;
+use thiserror::Error;
+use crate::grid::{Coord, Grid, GridError};
+
+#[derive(Debug, PartialEq, Error)]
+pub enum GardenError {
+    #[error(transparent)]
+    Grid(#[from] GridError),
+    #[error("Garden map has no starting position 'S'")]
+    MissingStart,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Garden {
+    // true for garden plots, false for rocks
+    plots: Grid<bool>,
+    pub start: Coord,
+}
+
+impl Garden {
+    pub fn width(&self) -> usize { self.plots.width() }
+    pub fn height(&self) -> usize { self.plots.height() }
+
+    pub fn is_plot(&self, c: Coord) -> bool {
+        self.plots.get(c).copied().unwrap_or(false)
+    }
+    /// Whether the plot at (`x`,`y`) of the garden map repeated infinitely in every direction
+    pub fn is_plot_tiled(&self, x: isize, y: isize) -> bool {
+        let (w, h) = (self.width() as isize, self.height() as isize);
+        self.plots[Coord::new(x.rem_euclid(w) as usize, y.rem_euclid(h) as usize)]
+    }
+    pub fn neighbours(&self, c: Coord) -> impl Iterator<Item=Coord> + '_ {
+        self.plots.neighbours4(c).filter(|&n| self.plots[n])
+    }
+}
+
+impl FromStr for Garden {
+    type Err = GardenError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let tiles = Grid::parse_with(s, |c| matches!(c, '.' | '#' | 'S').then_some(c))?;
+        let start = tiles.iter()
+            .position(|&c| c == 'S')
+            .map(|idx| tiles.coord_of(idx))
+            .ok_or(GardenError::MissingStart)?;
+
+        Ok(Garden { plots: tiles.map(|&c| c != '#'), start })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_garden() {
+        let inp = std::fs::read_to_string("src/bin/day21/sample.txt").expect("Ops!");
+        let garden = inp.parse::<Garden>().expect("Ops!");
+
+        assert_eq!((garden.width(), garden.height()), (11, 11));
+        assert_eq!(garden.start, Coord::new(5, 5));
+        assert!(garden.is_plot(garden.start));
+        assert!(!garden.is_plot(Coord::new(5, 1)));
+        assert!(garden.is_plot_tiled(-6, -6) && !garden.is_plot_tiled(16, -10));
+
+        assert_eq!("..\n..".parse::<Garden>(), Err(GardenError::MissingStart));
+    }
+}
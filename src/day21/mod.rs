@@ -0,0 +1,28 @@
+pub mod garden;
+pub mod walker;
+
+use garden::Garden;
+use walker::{reachable, reachable_extrapolated};
+use crate::Solution;
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    const DAY: u8 = 21;
+    type Input = Garden;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Garden {
+        input.parse::<Garden>().expect("Ops!")
+    }
+    fn part1(garden: &Garden) -> usize {
+        reachable(garden, 64)
+    }
+    fn part2(garden: &Garden) -> usize {
+        match reachable_extrapolated(garden, 26_501_365) {
+            Ok(plots) => plots,
+            Err(e) => panic!("{}",e),
+        }
+    }
+}
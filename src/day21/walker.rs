@@ -0,0 +1,132 @@
+use std::collections::{HashSet, VecDeque};
+use thiserror::Error;
+use crate::{day21::garden::Garden, grid::Coord};
+
+pub type Steps = usize;
+
+#[derive(Debug, PartialEq, Error)]
+pub enum ExtrapolationError {
+    #[error("Garden map is {0}x{1} rather than square")]
+    NotSquare(usize, usize),
+    #[error("Start ({},{}) isn't in the middle of the map", .0.x, .0.y)]
+    OffCentre(Coord),
+    #[error("Rock at ({},{}) blocks the way out from the start to the edges of the map", .0.x, .0.y)]
+    Blocked(Coord),
+    #[error("Reachable plots don't grow quadratically, extrapolating to {0}")]
+    NotQuadratic(i128),
+}
+
+/// Plots the elf can end up on after exactly `steps` steps within the garden's bounds
+///
+/// The elf can step back and forth, hence every plot reached in fewer steps of the same parity counts too
+pub fn reachable(garden: &Garden, steps: Steps) -> usize {
+    let mut seen = HashSet::from([garden.start]);
+    let mut queue = VecDeque::from([(garden.start, 0)]);
+    let mut count = 0;
+
+    while let Some((c, d)) = queue.pop_front() {
+        if d % 2 == steps % 2 { count += 1 }
+        if d == steps { continue }
+        for n in garden.neighbours(c) {
+            if seen.insert(n) { queue.push_back((n, d + 1)) }
+        }
+    }
+    count
+}
+
+/// Same as [`reachable`] but on the garden map repeated infinitely in every direction
+pub fn reachable_tiled(garden: &Garden, steps: Steps) -> usize {
+    let start = (garden.start.x as isize, garden.start.y as isize);
+    let mut seen = HashSet::from([start]);
+    let mut frontier = vec![start];
+    let mut next = vec![];
+    // plots reached with an even and with an odd number of steps
+    let mut count = [1, 0];
+
+    for d in 1..=steps {
+        for (x, y) in frontier.drain(..) {
+            for n in [(x, y - 1), (x + 1, y), (x, y + 1), (x - 1, y)] {
+                if garden.is_plot_tiled(n.0, n.1) && seen.insert(n) { next.push(n) }
+            }
+        }
+        count[d % 2] += next.len();
+        std::mem::swap(&mut frontier, &mut next);
+    }
+    count[steps % 2]
+}
+
+/// Extrapolates [`reachable_tiled`] for a large number of steps
+///
+/// With the start in the middle of a square map and a clear path out to its edges, the reachable area grows
+/// quadratically every time the elf crosses another map width, hence three exact counts are enough
+pub fn reachable_extrapolated(garden: &Garden, steps: Steps) -> Result<usize, ExtrapolationError> {
+    let (w, h) = (garden.width(), garden.height());
+    if w != h { return Err(ExtrapolationError::NotSquare(w, h)) }
+    if w % 2 == 0 || garden.start != Coord::new(w / 2, h / 2) { return Err(ExtrapolationError::OffCentre(garden.start)) }
+    let row = (0..w).map(|x| Coord::new(x, garden.start.y));
+    let column = (0..h).map(|y| Coord::new(garden.start.x, y));
+    if let Some(rock) = row.chain(column).find(|&c| !garden.is_plot(c)) {
+        return Err(ExtrapolationError::Blocked(rock))
+    }
+
+    let (n, rem) = (steps / w, steps % w);
+    if n < 3 { return Ok(reachable_tiled(garden, steps)) }
+
+    let [f0, f1, f2] = [rem, rem + w, rem + 2 * w].map(|s| reachable_tiled(garden, s) as i128);
+    // Newton's forward differences, where f(k) = f0 + k * d1 + k * (k-1) / 2 * d2
+    let (n, d1, d2) = (n as i128, f1 - f0, f2 + f0 - 2 * f1);
+    let plots = f0 + n * d1 + n * (n - 1) / 2 * d2;
+    usize::try_from(plots).map_err(|_| ExtrapolationError::NotQuadratic(plots))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn sample() -> Garden {
+        let inp = std::fs::read_to_string("src/bin/day21/sample.txt").expect("Ops!");
+        inp.parse::<Garden>().expect("Ops!")
+    }
+
+    #[test]
+    fn test_reachable() {
+        let garden = sample();
+        assert_eq!(reachable(&garden, 1), 2);
+        assert_eq!(reachable(&garden, 2), 4);
+        assert_eq!(reachable(&garden, 3), 6);
+        assert_eq!(reachable(&garden, 6), 16);
+    }
+
+    #[test]
+    fn test_reachable_tiled() {
+        let garden = sample();
+        // within 5 steps the elf can't leave the map
+        for steps in 0..=5 {
+            assert_eq!(reachable_tiled(&garden, steps), reachable(&garden, steps));
+        }
+        assert_eq!(reachable_tiled(&garden, 6), 16);
+        assert_eq!(reachable_tiled(&garden, 10), 50);
+    }
+
+    #[test]
+    fn test_reachable_extrapolated() {
+        // like the puzzle input, the middle row & column and the edges of the map are clear of rocks
+        let garden = std::fs::read_to_string("src/bin/day21/sample1.txt").expect("Ops!")
+            .parse::<Garden>()
+            .expect("Ops!");
+        for steps in [5 * 11 + 5, 7 * 11 + 5, 8 * 11 + 2, 9 * 11 + 7, 10 * 11] {
+            assert_eq!(reachable_extrapolated(&garden, steps), Ok(reachable_tiled(&garden, steps)));
+        }
+    }
+
+    #[test]
+    fn test_reachable_extrapolated_errors() {
+        assert_eq!(reachable_extrapolated(&sample(), 1000), Err(ExtrapolationError::Blocked(Coord::new(1, 5))));
+
+        let garden = |s: &str| s.parse::<Garden>().expect("Ops!");
+        assert_eq!(reachable_extrapolated(&garden("...\n.S.\n...\n..."), 1000), Err(ExtrapolationError::NotSquare(3, 4)));
+        assert_eq!(reachable_extrapolated(&garden("...\nS..\n..."), 1000), Err(ExtrapolationError::OffCentre(Coord::new(0, 1))));
+        assert_eq!(reachable_extrapolated(&garden("....\n....\n..S.\n...."), 1000), Err(ExtrapolationError::OffCentre(Coord::new(2, 2))));
+        assert_eq!(reachable_extrapolated(&garden(".#.\n.S.\n..."), 1000), Err(ExtrapolationError::Blocked(Coord::new(1, 0))));
+    }
+}
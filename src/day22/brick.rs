@@ -0,0 +1,91 @@
+use std::{fmt::Debug, num::ParseIntError, ops::RangeInclusive, str::FromStr};
+use thiserror::Error;
+
+pub type Unit = usize;
+
+#[derive(Debug, PartialEq, Error)]
+pub enum BrickError {
+    #[error("Expected `x,y,z~x,y,z`, found [{0}]")]
+    Malformed(String),
+    #[error("Invalid coordinate in [{0}]: {1}")]
+    InvalidCoordinate(String, ParseIntError),
+    #[error("Brick [{0}] reaches into the ground at z=0")]
+    Grounded(String),
+    #[error("Bricks [{0}] and [{1}] share a cube")]
+    Overlapping(String, String),
+}
+
+/// A brick of sand cubes, with its two ends given as `x,y,z` in any order
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Brick {
+    pub from: [Unit; 3],
+    pub to: [Unit; 3],
+}
+
+impl Brick {
+    pub fn bottom(&self) -> Unit {
+        self.from[2].min(self.to[2])
+    }
+    pub fn height(&self) -> Unit {
+        self.from[2].abs_diff(self.to[2]) + 1
+    }
+    fn span(&self, axis: usize) -> RangeInclusive<Unit> {
+        self.from[axis].min(self.to[axis]) ..= self.from[axis].max(self.to[axis])
+    }
+    /// The `(x, y)` positions the brick covers when looking from above
+    pub fn footprint(&self) -> impl Iterator<Item=(Unit, Unit)> + '_ {
+        self.span(0).flat_map(move |x| self.span(1).map(move |y| (x, y)))
+    }
+    /// Moves the brick vertically so that its lowest cube lies at `z`
+    pub fn drop_to(&mut self, z: Unit) {
+        let dz = self.bottom() - z;
+        self.from[2] -= dz;
+        self.to[2] -= dz;
+    }
+}
+
+impl FromStr for Brick {
+    type Err = BrickError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // 1,0,1~1,2,1
+        let point = |p: &str| -> Result<[Unit; 3], BrickError> {
+            let v = p.split(',')
+                .map(|n| n.trim().parse::<Unit>().map_err(|e| BrickError::InvalidCoordinate(s.into(), e)))
+                .collect::<Result<Vec<_>,_>>()?;
+            v.try_into().map_err(|_| BrickError::Malformed(s.into()))
+        };
+        let (from, to) = s.split_once('~').ok_or_else(|| BrickError::Malformed(s.into()))?;
+        let brick = Brick { from: point(from)?, to: point(to)? };
+        if brick.bottom() == 0 { return Err(BrickError::Grounded(s.into())) }
+        Ok(brick)
+    }
+}
+
+impl Debug for Brick {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let ([x1, y1, z1], [x2, y2, z2]) = (self.from, self.to);
+        write!(f, "{x1},{y1},{z1}~{x2},{y2},{z2}")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_brick() {
+        let brick = "0,0,10~1,0,10".parse::<Brick>().expect("Ops!");
+        assert_eq!(brick, Brick { from: [0, 0, 10], to: [1, 0, 10] });
+        assert_eq!(format!("{:?}", brick), "0,0,10~1,0,10");
+        assert_eq!(brick.footprint().collect::<Vec<_>>(), [(0, 0), (1, 0)]);
+
+        let brick = "1,1,9~1,1,8".parse::<Brick>().expect("Ops!");
+        assert_eq!((brick.bottom(), brick.height()), (8, 2));
+
+        assert_eq!("1,1,9".parse::<Brick>(), Err(BrickError::Malformed("1,1,9".into())));
+        assert_eq!("1,1~1,1".parse::<Brick>(), Err(BrickError::Malformed("1,1~1,1".into())));
+        assert!(matches!("1,x,9~1,1,8".parse::<Brick>(), Err(BrickError::InvalidCoordinate(..))));
+        assert_eq!("1,1,0~1,1,2".parse::<Brick>(), Err(BrickError::Grounded("1,1,0~1,1,2".into())));
+    }
+}
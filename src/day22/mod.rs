@@ -0,0 +1,28 @@
+pub mod brick;
+pub mod stack;
+
+use stack::Stack;
+use crate::Solution;
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    const DAY: u8 = 22;
+    type Input = Stack;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Stack {
+        input.parse::<Stack>().expect("Ops!")
+    }
+    fn part1(stack: &Stack) -> usize {
+        (0..stack.len())
+            .filter(|&b| stack.can_disintegrate(b))
+            .count()
+    }
+    fn part2(stack: &Stack) -> usize {
+        (0..stack.len())
+            .map(|b| stack.chain_reaction(b))
+            .sum()
+    }
+}
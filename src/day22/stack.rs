@@ -0,0 +1,160 @@
+use std::{collections::{HashMap, VecDeque}, rc::Rc, str::FromStr};
+use crate::day22::brick::{Brick, BrickError, Unit};
+
+/// Bricks settled on the ground, along with which bricks rest on which
+#[derive(Debug)]
+pub struct Stack {
+    pub bricks: Rc<[Brick]>,
+    // bricks resting directly on top of each brick
+    supports: Vec<Vec<usize>>,
+    // bricks each brick rests directly on
+    supported_by: Vec<Vec<usize>>,
+}
+
+impl Stack {
+    /// Lets the falling bricks settle, lowest brick first, and records how they end up resting on each other
+    ///
+    /// Fails on a brick reaching into the ground or sharing a cube with another, neither of which can fall into place
+    pub fn settle(bricks: &[Brick]) -> Result<Stack, BrickError> {
+        let mut bricks = bricks.to_vec();
+        bricks.sort_by_key(|b| b.bottom());
+        let falling = bricks.clone();
+
+        // for every (x,y) the top z level reached so far and the brick at the top
+        let mut heights: HashMap<(Unit, Unit), (Unit, usize)> = HashMap::new();
+        let mut supports = vec![vec![]; bricks.len()];
+        let mut supported_by = vec![vec![]; bricks.len()];
+
+        for (id, brick) in bricks.iter_mut().enumerate() {
+            let below = brick.footprint()
+                .filter_map(|xy| heights.get(&xy))
+                .copied()
+                .collect::<Vec<_>>();
+            let rest = below.iter().map(|&(z, _)| z).max().unwrap_or(0);
+            // settled bricks only ever move down, so one reaching this brick's level shared a cube with it
+            if brick.bottom() <= rest {
+                return Err(match below.iter().find(|&&(z, _)| z == rest) {
+                    Some(&(_, b)) => BrickError::Overlapping(format!("{:?}", falling[b]), format!("{brick:?}")),
+                    None => BrickError::Grounded(format!("{brick:?}")),
+                })
+            }
+
+            let mut under = below.into_iter()
+                .filter(|&(z, _)| z == rest)
+                .map(|(_, b)| b)
+                .collect::<Vec<_>>();
+            under.sort();
+            under.dedup();
+            under.iter().for_each(|&b| supports[b].push(id));
+            supported_by[id] = under;
+
+            brick.drop_to(rest + 1);
+            let top = rest + brick.height();
+            brick.footprint().for_each(|xy| { heights.insert(xy, (top, id)); });
+        }
+
+        Ok(Stack { bricks: bricks.into(), supports, supported_by })
+    }
+    pub fn len(&self) -> usize {
+        self.bricks.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.bricks.is_empty()
+    }
+    pub fn supports(&self, brick: usize) -> &[usize] {
+        &self.supports[brick]
+    }
+    pub fn supported_by(&self, brick: usize) -> &[usize] {
+        &self.supported_by[brick]
+    }
+    /// A brick can be safely removed when every brick resting on it rests on another brick too
+    pub fn can_disintegrate(&self, brick: usize) -> bool {
+        self.supports[brick].iter().all(|&b| self.supported_by[b].len() > 1)
+    }
+    /// Number of other bricks that fall once `brick` is removed
+    pub fn chain_reaction(&self, brick: usize) -> usize {
+        // supporting bricks left under each brick
+        let mut left = self.supported_by.iter().map(|s| s.len()).collect::<Vec<_>>();
+        let mut queue = VecDeque::from([brick]);
+        let mut fallen = 0;
+
+        while let Some(b) = queue.pop_front() {
+            for &above in &self.supports[b] {
+                left[above] -= 1;
+                if left[above] == 0 {
+                    fallen += 1;
+                    queue.push_back(above);
+                }
+            }
+        }
+        fallen
+    }
+}
+
+impl FromStr for Stack {
+    type Err = BrickError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bricks = s.lines()
+            .map(|line| line.parse::<Brick>())
+            .collect::<Result<Vec<_>,_>>()?;
+        Stack::settle(&bricks)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn sample() -> Stack {
+        let inp = std::fs::read_to_string("src/bin/day22/sample.txt").expect("Ops!");
+        inp.parse::<Stack>().expect("Ops!")
+    }
+
+    #[test]
+    fn test_settle() {
+        let stack = sample();
+
+        // bricks are ordered as they fell, A to G
+        assert_eq!(
+            stack.bricks.iter().map(|b| b.bottom()).collect::<Vec<_>>(),
+            [1, 2, 2, 3, 3, 4, 5]
+        );
+        assert_eq!(stack.supports(0), [1, 2]);
+        assert_eq!(stack.supported_by(3), [1, 2]);
+        assert_eq!(stack.supported_by(5), [3, 4]);
+        assert_eq!(stack.supports(6), [] as [usize; 0]);
+    }
+
+    #[test]
+    fn test_settle_errors() {
+        assert_eq!(
+            "1,0,1~1,2,1\n0,0,2~2,0,2\n1,1,1~1,1,3".parse::<Stack>().err(),
+            Some(BrickError::Overlapping("1,0,1~1,2,1".into(), "1,1,1~1,1,3".into()))
+        );
+        // a brick may start right above another, but not at its top level
+        assert!("0,0,1~0,0,3\n0,0,4~0,0,5".parse::<Stack>().is_ok());
+        assert!(matches!("0,0,1~0,0,3\n0,0,3~0,0,5".parse::<Stack>(), Err(BrickError::Overlapping(..))));
+        // bricks built by hand skip the parser's check
+        let brick = Brick { from: [0, 0, 0], to: [0, 0, 1] };
+        assert_eq!(Stack::settle(&[brick]).err(), Some(BrickError::Grounded("0,0,0~0,0,1".into())));
+    }
+
+    #[test]
+    fn test_can_disintegrate() {
+        let stack = sample();
+        assert_eq!(
+            (0..stack.len()).map(|b| stack.can_disintegrate(b)).collect::<Vec<_>>(),
+            [false, true, true, true, true, false, true]
+        );
+    }
+
+    #[test]
+    fn test_chain_reaction() {
+        let stack = sample();
+        assert_eq!(
+            (0..stack.len()).map(|b| stack.chain_reaction(b)).collect::<Vec<_>>(),
+            [6, 0, 0, 0, 0, 1, 0]
+        );
+    }
+}
@@ -0,0 +1,83 @@
+use std::collections::HashMap;
+use crate::{day23::trails::Trails, grid::Coord};
+
+pub type Steps = usize;
+
+/// The trails reduced to the junctions and the lengths of the corridors joining them
+#[derive(Debug, Clone)]
+pub struct TrailGraph {
+    pub junctions: Vec<Coord>,
+    // junctions reachable from each junction, with the steps needed
+    edges: Vec<Vec<(usize, Steps)>>,
+    start: usize,
+    end: usize,
+}
+
+impl TrailGraph {
+    /// Walks every corridor leaving a junction until the next junction; with `slippery` slopes can only be walked downhill
+    pub fn new(trails: &Trails, slippery: bool) -> TrailGraph {
+        let junctions = trails.junctions().collect::<Vec<_>>();
+        let index = junctions.iter()
+            .enumerate()
+            .map(|(i, &c)| (c, i))
+            .collect::<HashMap<_,_>>();
+
+        let edges = junctions.iter()
+            .map(|&j| {
+                trails.neighbours(j, slippery)
+                    .filter_map(|first| {
+                        let (mut prev, mut cur, mut steps) = (j, first, 1);
+                        while !index.contains_key(&cur) {
+                            // a corridor has a single way forward, unless it's a dead end or a slope blocks the way
+                            let next = trails.neighbours(cur, slippery).find(|&n| n != prev)?;
+                            (prev, cur, steps) = (cur, next, steps + 1);
+                        }
+                        Some((index[&cur], steps))
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        TrailGraph { start: index[&trails.start], end: index[&trails.end], junctions, edges }
+    }
+    pub fn edges(&self, junction: usize) -> &[(usize, Steps)] {
+        &self.edges[junction]
+    }
+    /// Steps of the longest hike from start to end that never steps on the same tile twice
+    pub fn longest_hike(&self) -> Option<Steps> {
+        let mut visited = vec![false; self.junctions.len()];
+        self.longest_from(self.start, &mut visited)
+    }
+    fn longest_from(&self, junction: usize, visited: &mut [bool]) -> Option<Steps> {
+        if junction == self.end { return Some(0) }
+
+        visited[junction] = true;
+        let longest = self.edges[junction].iter()
+            .filter_map(|&(next, steps)| {
+                if visited[next] { return None }
+                self.longest_from(next, visited).map(|s| s + steps)
+            })
+            .max();
+        visited[junction] = false;
+        longest
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_trail_graph() {
+        let inp = std::fs::read_to_string("src/bin/day23/sample.txt").expect("Ops!");
+        let trails = inp.parse::<Trails>().expect("Ops!");
+
+        let graph = TrailGraph::new(&trails, false);
+        assert_eq!(graph.junctions.len(), 9);
+        // start leads to the first junction at (3,5) after 15 steps
+        assert_eq!(graph.edges(graph.start), [(graph.junctions.iter().position(|&c| c == Coord::new(3, 5)).unwrap(), 15)]);
+
+        assert_eq!(TrailGraph::new(&trails, true).longest_hike(), Some(94));
+        assert_eq!(graph.longest_hike(), Some(154));
+    }
+}
@@ -0,0 +1,25 @@
+pub mod trails;
+pub mod hike;
+
+use hike::TrailGraph;
+use trails::Trails;
+use crate::Solution;
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    const DAY: u8 = 23;
+    type Input = Trails;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Trails {
+        input.parse::<Trails>().expect("Ops!")
+    }
+    fn part1(trails: &Trails) -> usize {
+        TrailGraph::new(trails, true).longest_hike().expect("no hike reaches the end")
+    }
+    fn part2(trails: &Trails) -> usize {
+        TrailGraph::new(trails, false).longest_hike().expect("no hike reaches the end")
+    }
+}
@@ -0,0 +1,111 @@
+use std::str::FromStr;
+use thiserror::Error;
+use crate::grid::{Coord, Direction, Grid, GridError};
+
+#[derive(Debug, PartialEq, Error)]
+pub enum TrailsError {
+    #[error(transparent)]
+    Grid(#[from] GridError),
+    #[error("Expected a single path tile on the top and the bottom rows")]
+    MissingEntrance,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Tile {
+    Path,
+    Forest,
+    Slope(Direction),
+}
+
+impl Tile {
+    fn parse(c: char) -> Option<Tile> {
+        match c {
+            '.' => Some(Tile::Path),
+            '#' => Some(Tile::Forest),
+            '^' => Some(Tile::Slope(Direction::Up)),
+            '>' => Some(Tile::Slope(Direction::Right)),
+            'v' => Some(Tile::Slope(Direction::Down)),
+            '<' => Some(Tile::Slope(Direction::Left)),
+            _ => None,
+        }
+    }
+}
+
+/// Map of the hiking trails, entered on the top row and left on the bottom row
+#[derive(Debug, Clone, PartialEq)]
+pub struct Trails {
+    map: Grid<Tile>,
+    pub start: Coord,
+    pub end: Coord,
+}
+
+impl Trails {
+    pub fn width(&self) -> usize { self.map.width() }
+    pub fn height(&self) -> usize { self.map.height() }
+
+    pub fn tile(&self, c: Coord) -> Option<Tile> {
+        self.map.get(c).copied()
+    }
+    /// Tiles reachable in one step from `c`; with `slippery` slopes can only be left downhill
+    pub fn neighbours(&self, c: Coord, slippery: bool) -> impl Iterator<Item=Coord> + '_ {
+        let forced = match self.map[c] {
+            Tile::Slope(dir) if slippery => Some(dir),
+            _ => None,
+        };
+        Direction::ALL.into_iter()
+            .filter(move |&d| forced.is_none_or(|f| f == d))
+            .filter_map(move |d| self.map.move_from(c, d))
+            .filter(|&n| self.map[n] != Tile::Forest)
+    }
+    /// Tiles where the trail branches out, plus the start and end tiles
+    pub fn junctions(&self) -> impl Iterator<Item=Coord> + '_ {
+        (0..self.map.len())
+            .map(|idx| self.map.coord_of(idx))
+            .filter(|&c| {
+                self.map[c] != Tile::Forest
+                    && (c == self.start || c == self.end || self.neighbours(c, false).count() > 2)
+            })
+    }
+}
+
+impl FromStr for Trails {
+    type Err = TrailsError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let map = Grid::parse_with(s, Tile::parse)?;
+        let entrance = |y: usize| -> Result<Coord, TrailsError> {
+            let mut paths = map.row(y).iter()
+                .enumerate()
+                .filter(|(_, &t)| t == Tile::Path)
+                .map(|(x, _)| x);
+            match (paths.next(), paths.next()) {
+                (Some(x), None) => Ok(Coord::new(x, y)),
+                _ => Err(TrailsError::MissingEntrance),
+            }
+        };
+        let (start, end) = (entrance(0)?, entrance(map.height() - 1)?);
+        Ok(Trails { map, start, end })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_trails() {
+        let inp = std::fs::read_to_string("src/bin/day23/sample.txt").expect("Ops!");
+        let trails = inp.parse::<Trails>().expect("Ops!");
+
+        assert_eq!((trails.width(), trails.height()), (23, 23));
+        assert_eq!((trails.start, trails.end), (Coord::new(1, 0), Coord::new(21, 22)));
+        assert_eq!(trails.tile(Coord::new(10, 3)), Some(Tile::Slope(Direction::Right)));
+        assert_eq!(trails.junctions().count(), 9);
+
+        // the slope at (3,4) can only be left downhill
+        assert_eq!(trails.neighbours(Coord::new(3, 4), true).collect::<Vec<_>>(), [Coord::new(3, 5)]);
+        assert_eq!(trails.neighbours(Coord::new(3, 4), false).count(), 2);
+
+        assert_eq!("#.#\n...\n###".parse::<Trails>(), Err(TrailsError::MissingEntrance));
+    }
+}
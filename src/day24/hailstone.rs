@@ -0,0 +1,93 @@
+use std::{fmt::Debug, num::ParseIntError, str::FromStr};
+use thiserror::Error;
+
+pub type Unit = i64;
+
+#[derive(Debug, PartialEq, Error)]
+pub enum HailstoneError {
+    #[error("Expected `px, py, pz @ vx, vy, vz`, found [{0}]")]
+    Malformed(String),
+    #[error("Invalid number in [{0}]: {1}")]
+    InvalidNumber(String, ParseIntError),
+}
+
+/// A hailstone's position and velocity, per nanosecond
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Hailstone {
+    pub pos: [Unit; 3],
+    pub vel: [Unit; 3],
+}
+
+impl Hailstone {
+    pub fn at(&self, t: Unit) -> [Unit; 3] {
+        [0, 1, 2].map(|i| self.pos[i] + t * self.vel[i])
+    }
+    /// Where the paths of the two hailstones cross when ignoring the Z axis, if they cross in the future of both
+    pub fn crossing_xy(&self, other: &Hailstone) -> Option<(f64, f64)> {
+        let ([px, py, _], [vx, vy, _]) = (self.pos.map(i128::from), self.vel.map(i128::from));
+        let ([qx, qy, _], [ux, uy, _]) = (other.pos.map(i128::from), other.vel.map(i128::from));
+
+        // solve p + t * v = q + s * u for t and s with Cramer's rule
+        let det = ux * vy - vx * uy;
+        if det == 0 { return None }
+        let (dx, dy) = (qx - px, qy - py);
+        let t = (ux * dy - uy * dx) as f64 / det as f64;
+        let s = (vx * dy - vy * dx) as f64 / det as f64;
+
+        (t >= 0.0 && s >= 0.0).then_some((px as f64 + t * vx as f64, py as f64 + t * vy as f64))
+    }
+}
+
+impl FromStr for Hailstone {
+    type Err = HailstoneError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // 19, 13, 30 @ -2,  1, -2
+        let triple = |p: &str| -> Result<[Unit; 3], HailstoneError> {
+            let v = p.split(',')
+                .map(|n| n.trim().parse::<Unit>().map_err(|e| HailstoneError::InvalidNumber(s.into(), e)))
+                .collect::<Result<Vec<_>,_>>()?;
+            v.try_into().map_err(|_| HailstoneError::Malformed(s.into()))
+        };
+        let (pos, vel) = s.split_once('@').ok_or_else(|| HailstoneError::Malformed(s.into()))?;
+        Ok(Hailstone { pos: triple(pos)?, vel: triple(vel)? })
+    }
+}
+
+impl Debug for Hailstone {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let ([px, py, pz], [vx, vy, vz]) = (self.pos, self.vel);
+        write!(f, "{px}, {py}, {pz} @ {vx}, {vy}, {vz}")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_hailstone() {
+        let h = "19, 13, 30 @ -2,  1, -2".parse::<Hailstone>().expect("Ops!");
+        assert_eq!(h, Hailstone { pos: [19, 13, 30], vel: [-2, 1, -2] });
+        assert_eq!(format!("{:?}", h), "19, 13, 30 @ -2, 1, -2");
+        assert_eq!(h.at(5), [9, 18, 20]);
+
+        assert_eq!("19, 13 @ -2, 1".parse::<Hailstone>(), Err(HailstoneError::Malformed("19, 13 @ -2, 1".into())));
+        assert!(matches!("19, x, 30 @ -2, 1, -2".parse::<Hailstone>(), Err(HailstoneError::InvalidNumber(..))));
+    }
+
+    #[test]
+    fn test_crossing_xy() {
+        let a = "19, 13, 30 @ -2, 1, -2".parse::<Hailstone>().expect("Ops!");
+        let b = "18, 19, 22 @ -1, -1, -2".parse::<Hailstone>().expect("Ops!");
+        let c = "20, 19, 15 @ 1, -5, -3".parse::<Hailstone>().expect("Ops!");
+        let d = "20, 25, 34 @ -2, -2, -4".parse::<Hailstone>().expect("Ops!");
+
+        let (x, y) = a.crossing_xy(&b).expect("Ops!");
+        assert!((x - 14.333).abs() < 1e-3 && (y - 15.333).abs() < 1e-3);
+        // crossed in the past for hailstone a
+        assert_eq!(a.crossing_xy(&c), None);
+        // parallel paths
+        assert_eq!(b.crossing_xy(&d), None);
+    }
+}
@@ -0,0 +1,24 @@
+pub mod hailstone;
+pub mod storm;
+
+use storm::Storm;
+use crate::Solution;
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    const DAY: u8 = 24;
+    type Input = Storm;
+    type Part1 = usize;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Storm {
+        input.parse::<Storm>().expect("Ops!")
+    }
+    fn part1(storm: &Storm) -> usize {
+        storm.crossings_within(200_000_000_000_000.0..=400_000_000_000_000.0)
+    }
+    fn part2(storm: &Storm) -> i64 {
+        storm.rock().expect("no rock hits every hailstone").pos.iter().sum()
+    }
+}
@@ -0,0 +1,126 @@
+use std::{ops::RangeInclusive, str::FromStr};
+use num::{BigInt, BigRational, Signed, ToPrimitive, Zero};
+use crate::day24::hailstone::{Hailstone, HailstoneError, Unit};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Storm {
+    pub hail: Vec<Hailstone>,
+}
+
+impl Storm {
+    /// Pairs of hailstones whose future paths cross within `area` on both X and Y, ignoring the Z axis
+    pub fn crossings_within(&self, area: RangeInclusive<f64>) -> usize {
+        self.hail.iter()
+            .enumerate()
+            .flat_map(|(i, a)| self.hail[i + 1..].iter().map(move |b| (a, b)))
+            .filter_map(|(a, b)| a.crossing_xy(b))
+            .filter(|(x, y)| area.contains(x) && area.contains(y))
+            .count()
+    }
+    /// Position and velocity of a rock that, thrown once, hits every hailstone
+    ///
+    /// The rock hits hailstone `i` when `(p - pi) x (v - vi) = 0`. Subtracting the equations of two hailstones
+    /// cancels the non-linear `p x v` term and leaves three linear equations in `p` & `v`, hence three hailstones
+    /// give a 6x6 linear system, solved exactly over rational numbers
+    pub fn rock(&self) -> Option<Hailstone> {
+        let first = self.hail.first()?;
+        // some triplets may give a singular system, e.g. with parallel hailstones
+        (1..self.hail.len())
+            .flat_map(|j| (j + 1..self.hail.len()).map(move |k| (j, k)))
+            .find_map(|(j, k)| {
+                let rows = [&self.hail[j], &self.hail[k]].into_iter()
+                    .flat_map(|other| equations(first, other))
+                    .collect::<Vec<_>>();
+                let solution = solve(rows)?;
+                let s = solution.iter()
+                    .map(|r| r.is_integer().then(|| r.to_integer().to_i64()).flatten())
+                    .collect::<Option<Vec<Unit>>>()?;
+                let rock = Hailstone { pos: [s[0], s[1], s[2]], vel: [s[3], s[4], s[5]] };
+                self.hail.iter().all(|h| hits(&rock, h)).then_some(rock)
+            })
+    }
+}
+
+/// Three rows `[px, py, pz, vx, vy, vz | rhs]` of `p x (vj - vi) + (pj - pi) x v = pj x vj - pi x vi`
+fn equations(i: &Hailstone, j: &Hailstone) -> [[BigInt; 7]; 3] {
+    let sub = |a: [Unit; 3], b: [Unit; 3]| [0, 1, 2].map(|n| BigInt::from(a[n]) - BigInt::from(b[n]));
+    let cross = |a: [Unit; 3], b: [Unit; 3]| {
+        let (a, b) = (a.map(BigInt::from), b.map(BigInt::from));
+        [&a[1] * &b[2] - &a[2] * &b[1], &a[2] * &b[0] - &a[0] * &b[2], &a[0] * &b[1] - &a[1] * &b[0]]
+    };
+    let [dx, dy, dz] = sub(j.vel, i.vel);
+    let [ex, ey, ez] = sub(j.pos, i.pos);
+    let (cj, ci) = (cross(j.pos, j.vel), cross(i.pos, i.vel));
+    let [rx, ry, rz] = [0, 1, 2].map(|n| &cj[n] - &ci[n]);
+    let zero = BigInt::zero;
+
+    [
+        [zero(), dz.clone(), -dy.clone(), zero(), -ez.clone(), ey.clone(), rx],
+        [-dz, zero(), dx.clone(), ez, zero(), -ex.clone(), ry],
+        [dy, -dx, zero(), -ey, ex, zero(), rz],
+    ]
+}
+
+/// Gauss-Jordan elimination of an augmented `n x (n+1)` matrix; `None` when the system is singular
+fn solve(rows: Vec<[BigInt; 7]>) -> Option<Vec<BigRational>> {
+    let mut m = rows.into_iter()
+        .map(|row| row.map(BigRational::from_integer))
+        .collect::<Vec<_>>();
+    let n = m.len();
+
+    for col in 0..n {
+        let pivot = (col..n)
+            .filter(|&r| !m[r][col].is_zero())
+            .max_by(|&a, &b| m[a][col].abs().cmp(&m[b][col].abs()))?;
+        m.swap(col, pivot);
+        let p = m[col][col].clone();
+        m[col].iter_mut().for_each(|v| *v /= &p);
+
+        let pivot_row = m[col].clone();
+        for (_, row) in m.iter_mut().enumerate().filter(|(r, _)| *r != col) {
+            let factor = row[col].clone();
+            if factor.is_zero() { continue }
+            row.iter_mut().zip(&pivot_row).skip(col).for_each(|(v, p)| *v -= &factor * p);
+        }
+    }
+    Some(m.into_iter().map(|row| row[n].clone()).collect())
+}
+
+/// Whether the rock and the hailstone are at the same position at the same non-negative time
+fn hits(rock: &Hailstone, h: &Hailstone) -> bool {
+    let Some(i) = (0..3).find(|&i| rock.vel[i] != h.vel[i]) else { return rock.pos == h.pos };
+    let (dp, dv) = (h.pos[i] - rock.pos[i], rock.vel[i] - h.vel[i]);
+    dp % dv == 0 && dp / dv >= 0 && rock.at(dp / dv) == h.at(dp / dv)
+}
+
+impl FromStr for Storm {
+    type Err = HailstoneError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Storm {
+            hail: s.lines().map(|line| line.parse::<Hailstone>()).collect::<Result<Vec<_>,_>>()?
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn sample() -> Storm {
+        let inp = std::fs::read_to_string("src/bin/day24/sample.txt").expect("Ops!");
+        inp.parse::<Storm>().expect("Ops!")
+    }
+
+    #[test]
+    fn test_crossings_within() {
+        assert_eq!(sample().crossings_within(7.0..=27.0), 2);
+    }
+
+    #[test]
+    fn test_rock() {
+        let rock = sample().rock().expect("Ops!");
+        assert_eq!(rock, Hailstone { pos: [24, 13, 10], vel: [-3, 1, 2] });
+        assert_eq!(rock.pos.iter().sum::<Unit>(), 47);
+    }
+}
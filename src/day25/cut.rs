@@ -0,0 +1,94 @@
+use std::collections::{HashMap, VecDeque};
+use crate::day25::wiring::Wiring;
+
+/// Sizes of the two groups left after disconnecting exactly `wires` wires, if the components can be split that way
+///
+/// Every component ends up on the other side of the cut to some component, hence the first component is taken as
+/// the source and every other one is tried as the sink. A max-flow of `wires` means the cut is found, and the source's
+/// group is whatever remains reachable in the residual graph; a sink cut off by fewer wires is passed over
+pub fn split_groups(wiring: &Wiring, wires: usize) -> Option<(usize, usize)> {
+    (1..wiring.len()).find_map(|sink| {
+        let mut flow = Flow::default();
+        // stop at `wires` + 1 paths, which put source & sink on the same side of the cut
+        let paths = (0..=wires).take_while(|_| flow.augment(wiring, 0, sink)).count();
+        if paths != wires { return None }
+        let group = flow.reachable(wiring, 0);
+        Some((group, wiring.len() - group))
+    })
+}
+
+/// Flow through the unit capacity wires, per direction
+#[derive(Default)]
+struct Flow(HashMap<(usize, usize), i32>);
+
+impl Flow {
+    fn residual(&self, a: usize, b: usize) -> i32 {
+        1 - self.0.get(&(a, b)).copied().unwrap_or(0)
+    }
+    /// Pushes one more unit of flow along the shortest path with spare capacity, Edmonds-Karp style
+    fn augment(&mut self, wiring: &Wiring, source: usize, sink: usize) -> bool {
+        let mut prev = vec![None; wiring.len()];
+        prev[source] = Some(source);
+        let mut queue = VecDeque::from([source]);
+
+        while let Some(c) = queue.pop_front() {
+            if c == sink { break }
+            for &n in wiring.wires(c) {
+                if prev[n].is_none() && self.residual(c, n) > 0 {
+                    prev[n] = Some(c);
+                    queue.push_back(n);
+                }
+            }
+        }
+        if prev[sink].is_none() { return false }
+
+        let mut c = sink;
+        while c != source {
+            let p = prev[c].unwrap();
+            *self.0.entry((p, c)).or_default() += 1;
+            *self.0.entry((c, p)).or_default() -= 1;
+            c = p;
+        }
+        true
+    }
+    /// Number of components reachable from `source` through wires with spare capacity
+    fn reachable(&self, wiring: &Wiring, source: usize) -> usize {
+        let mut seen = vec![false; wiring.len()];
+        seen[source] = true;
+        let mut queue = VecDeque::from([source]);
+        let mut count = 0;
+
+        while let Some(c) = queue.pop_front() {
+            count += 1;
+            for &n in wiring.wires(c) {
+                if !seen[n] && self.residual(c, n) > 0 {
+                    seen[n] = true;
+                    queue.push_back(n);
+                }
+            }
+        }
+        count
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_split_groups() {
+        let inp = std::fs::read_to_string("src/bin/day25/sample.txt").expect("Ops!");
+        let wiring = inp.parse::<Wiring>().expect("Ops!");
+
+        let (a, b) = split_groups(&wiring, 3).expect("Ops!");
+        assert_eq!((a.min(b), a.max(b)), (6, 9));
+        // the components are too tightly wired to be split by cutting two wires only
+        assert_eq!(split_groups(&wiring, 2), None);
+
+        // two triangles joined by a single wire fall in two losing one wire, or two wires cutting off `a`, but never three
+        let wiring = "a: b c\nb: c\nc: d\nd: e f\ne: f".parse::<Wiring>().expect("Ops!");
+        assert_eq!(split_groups(&wiring, 1), Some((3, 3)));
+        assert_eq!(split_groups(&wiring, 2), Some((1, 5)));
+        assert_eq!(split_groups(&wiring, 3), None);
+    }
+}
@@ -0,0 +1,89 @@
+use std::{collections::HashMap, rc::Rc, str::FromStr};
+use thiserror::Error;
+
+#[derive(Debug, PartialEq, Error)]
+pub enum WiringError {
+    #[error("Line {line}: expected `<component>: <components>`, found [{text}]")]
+    Malformed { line: usize, text: String },
+}
+
+/// Components and the wires connecting them, as an undirected graph
+#[derive(Debug, Clone, PartialEq)]
+pub struct Wiring {
+    pub names: Vec<Rc<str>>,
+    // components wired to each component
+    wires: Vec<Vec<usize>>,
+}
+
+impl Wiring {
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+    pub fn index_of(&self, name: &str) -> Option<usize> {
+        self.names.iter().position(|n| n.as_ref() == name)
+    }
+    pub fn wires(&self, component: usize) -> &[usize] {
+        &self.wires[component]
+    }
+    pub fn wire_count(&self) -> usize {
+        self.wires.iter().map(|w| w.len()).sum::<usize>() / 2
+    }
+}
+
+impl FromStr for Wiring {
+    type Err = WiringError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // jqt: rhn xhk nvd
+        let mut names = vec![];
+        let mut index = HashMap::<Rc<str>, usize>::new();
+        let mut wires = vec![];
+        let mut id = |name: &str, wires: &mut Vec<Vec<usize>>| -> usize {
+            *index.entry(name.into()).or_insert_with(|| {
+                names.push(Rc::from(name));
+                wires.push(vec![]);
+                names.len() - 1
+            })
+        };
+
+        for (i, line) in s.lines().enumerate() {
+            let malformed = || WiringError::Malformed { line: i + 1, text: line.into() };
+            let (from, to) = line.split_once(": ").ok_or_else(malformed)?;
+            if from.is_empty() || to.trim().is_empty() { return Err(malformed()) }
+
+            let a = id(from, &mut wires);
+            for other in to.split_whitespace() {
+                let b = id(other, &mut wires);
+                wires[a].push(b);
+                wires[b].push(a);
+            }
+        }
+        Ok(Wiring { names, wires })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_wiring() {
+        let inp = std::fs::read_to_string("src/bin/day25/sample.txt").expect("Ops!");
+        let wiring = inp.parse::<Wiring>().expect("Ops!");
+
+        assert_eq!(wiring.len(), 15);
+        assert_eq!(wiring.wire_count(), 33);
+        let jqt = wiring.index_of("jqt").expect("Ops!");
+        let mut wired = wiring.wires(jqt).iter().map(|&c| wiring.names[c].as_ref()).collect::<Vec<_>>();
+        wired.sort();
+        assert_eq!(wired, ["ntq", "nvd", "rhn", "xhk"]);
+
+        assert_eq!(
+            "jqt: rhn\nrsh frs".parse::<Wiring>(),
+            Err(WiringError::Malformed { line: 2, text: "rsh frs".into() })
+        );
+    }
+}
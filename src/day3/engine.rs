@@ -2,24 +2,24 @@ use std::fmt::Display;
 use std::num::ParseIntError;
 use std::str::FromStr;
 use std::rc::Rc;
+use crate::grid::Grid;
 use super::parts::*;
 
 #[derive(Debug)]
-pub(crate) struct EngineSchematic {
-    pub(crate) len: usize,
-    pub(crate) partnums: Rc<[PartNumber]>,
-    pub(crate) symbols: Rc<[Symbol]>
+pub struct EngineSchematic {
+    pub layout: Grid<char>,
+    pub partnums: Rc<[PartNumber]>,
+    pub symbols: Rc<[Symbol]>
 }
 
 impl EngineSchematic {
-    pub(crate) fn part_numbers(&self) -> impl Iterator<Item=&PartNumber> + '_ {
-        let len = self.len;
+    pub fn part_numbers(&self) -> impl Iterator<Item=&PartNumber> + '_ {
         self.partnums.iter()
             .filter(move |pn| {
-                self.symbols.iter().any(|s| pn.is_touching(s,len))
+                self.symbols.iter().any(|s| pn.is_touching(s,&self.layout))
             })
     }
-    pub(crate) fn get_gears_part_numbers(&self, gear: char) -> impl Iterator<Item=Rc<[&PartNumber]>> {
+    pub fn get_gears_part_numbers(&self, gear: char) -> impl Iterator<Item=Rc<[&PartNumber]>> {
         self.symbols
             .iter()
             // only proceed with gear symbol provided
@@ -30,7 +30,7 @@ impl EngineSchematic {
                 let pns = self.partnums.iter()
                     // only consider part numbers proximate to the gear
                     // ignore part numbers falling outside the gears reach
-                    .filter(|pn| s.is_touching(pn,self.len))
+                    .filter(|pn| s.is_touching(pn,&self.layout))
                     // .inspect(|d| println!("{:?}",d))
                     .collect::<Rc<_>>();
 
@@ -43,7 +43,8 @@ impl EngineSchematic {
 #[derive(Debug,PartialEq)]
 pub enum ErrorEngineSchematic {
     PartNumberTooLarge,
-    ParsedEmptyInput
+    ParsedEmptyInput,
+    RaggedLayout
 }
 
 impl Display for ErrorEngineSchematic {
@@ -51,6 +52,7 @@ impl Display for ErrorEngineSchematic {
         match self {
             ErrorEngineSchematic::PartNumberTooLarge => write!(f, "PartNumber found exceeds 32bit size"),
             ErrorEngineSchematic::ParsedEmptyInput => write!(f, "Parsed input potentialy empty"),
+            ErrorEngineSchematic::RaggedLayout => write!(f, "Schematic lines differ in length"),
         }
     }
 }
@@ -116,8 +118,14 @@ impl FromStr for EngineSchematic {
                         buf.clear();
                     }
                 }
-                // capture partnumber digit
-                '0'..='9' => buf.push(c),
+                // capture partnumber digit, a partnumber never continues onto the next line
+                '0'..='9' => {
+                    if c.0 % len == 0 && !buf.is_empty() {
+                        partnums.push( make_part_number(&buf)? );
+                        buf.clear();
+                    }
+                    buf.push(c)
+                },
                 // it should be a symbol if not a digit or '.' hence capture the
                 _ => {
                     symbols.push(c.into());
@@ -130,7 +138,13 @@ impl FromStr for EngineSchematic {
             }
         }
 
-        Ok(EngineSchematic { len, partnums: partnums.into(), symbols: symbols.into() } )
+        if !buf.is_empty() {
+            partnums.push( make_part_number(&buf)? );
+        }
+        let layout = Grid::new(len, schematic.len() / len, schematic.chars().collect::<Vec<_>>())
+            .map_err(|_| ErrorEngineSchematic::RaggedLayout)?;
+
+        Ok(EngineSchematic { layout, partnums: partnums.into(), symbols: symbols.into() } )
     }
 }
 
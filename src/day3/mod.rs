@@ -0,0 +1,28 @@
+pub mod engine;
+pub mod parts;
+
+use engine::*;
+use crate::Solution;
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+    type Input = EngineSchematic;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> EngineSchematic {
+        input.parse::<EngineSchematic>().expect("Ops!")
+    }
+    fn part1(es: &EngineSchematic) -> u32 {
+        es.part_numbers()
+            .map(|pn| pn.number)
+            .sum::<u32>()
+    }
+    fn part2(es: &EngineSchematic) -> u32 {
+        es.get_gears_part_numbers('*')
+            .map(|d| d.iter().map(|d| d.number).product::<u32>())
+            .sum::<u32>()
+    }
+}
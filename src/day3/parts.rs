@@ -0,0 +1,33 @@
+use std::ops::RangeInclusive;
+use crate::grid::Grid;
+
+#[derive(Debug)]
+pub struct PartNumber {
+    pub number: u32,
+    pub pos: RangeInclusive<usize>
+}
+
+impl PartNumber {
+    pub fn is_touching(&self, s: &Symbol, layout: &Grid<char>) -> bool {
+        s.is_touching(self,layout)
+    }
+}
+
+#[derive(Debug)]
+pub struct Symbol(
+    pub usize,
+    pub char
+);
+
+impl Symbol {
+    pub fn is_touching(&self, pn: &PartNumber, layout: &Grid<char>) -> bool {
+        layout.neighbours8(layout.coord_of(self.0))
+            .any(|c| pn.pos.contains(&layout.index_of(c)))
+    }
+}
+
+impl From<(usize,char)> for Symbol {
+    fn from(value: (usize, char)) -> Self {
+        Symbol(value.0,value.1)
+    }
+}
@@ -1,15 +1,15 @@
 use super::numbers::{Numbers, NumbersErrors as NE};
 use std::{fmt::Display, str::FromStr};
-use crate::card::CardError::{InvalidNumericValue, MalformedCardNumbers};
+use crate::day4::card::CardError::{InvalidNumericValue, MalformedCardNumbers};
 
 #[derive(Debug)]
-pub(crate) struct Card {
-    pub(crate) id: u32,
-    pub(crate) elf_nums: Numbers
+pub struct Card {
+    pub id: u32,
+    pub elf_nums: Numbers
 }
 
 impl Card {
-    pub(crate) fn winning_numbers<'a>(&'a self, win_nums: &'a Numbers) -> impl Iterator<Item=&'a u32> {
+    pub fn winning_numbers<'a>(&'a self, win_nums: &'a Numbers) -> impl Iterator<Item=&'a u32> {
         self.elf_nums.0.intersection(&win_nums.0)
     }
 }
@@ -74,9 +74,9 @@ impl FromStr for Card {
 }
 
 
-pub(crate) struct Rounds;
+pub struct Rounds;
 impl Rounds {
-    pub(crate) fn parse_rounds(input: &str) -> impl Iterator<Item=(Card, Numbers)> + '_ {
+    pub fn parse_rounds(input: &str) -> impl Iterator<Item=(Card, Numbers)> + '_ {
         input.lines()
             .map(|line| {
                 let mut split = line.split('|');
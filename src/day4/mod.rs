@@ -0,0 +1,47 @@
+pub mod card;
+pub mod numbers;
+
+use std::{collections::HashMap, rc::Rc};
+use card::{Card, Rounds};
+use numbers::Numbers;
+use crate::Solution;
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+    type Input = Rc<[(Card, Numbers)]>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Self::Input {
+        Rounds::parse_rounds(input).collect::<Rc<_>>()
+    }
+    fn part1(rounds: &Self::Input) -> u32 {
+        rounds.iter()
+            .map(|(card, numbers)| card.winning_numbers(numbers).count())
+            .filter(|&size| size > 0)
+            .map(|size| 2_u32.pow((size - 1) as u32))
+            .sum::<u32>()
+    }
+    fn part2(rounds: &Self::Input) -> u32 {
+        let mut copies = rounds.iter()
+            .map(|(card,_)| (card.id,1))
+            .collect::<HashMap<u32,u32>>();
+
+        rounds.iter()
+            .map(|(card, numbers)| {
+                let winning_numbers = card.winning_numbers(numbers).count() as u32;
+                (card,winning_numbers)
+            })
+            .map(|(card, wins)| {
+                let card_copies = *copies.get(&card.id).unwrap();
+                (card.id+1 ..= card.id + wins)
+                    .for_each(|next_card| {
+                        copies.entry(next_card).and_modify( |next_card_copies| *next_card_copies += card_copies);
+                });
+                card_copies
+            })
+            .sum::<u32>()
+    }
+}
@@ -33,7 +33,7 @@ impl From<ParseIntError> for NumbersErrors {
     }
 }
 #[derive(Debug)]
-pub(crate) struct Numbers(pub(crate) HashSet<u32>);
+pub struct Numbers(pub HashSet<u32>);
 impl FromStr for Numbers {
     type Err = NumbersErrors;
 
@@ -6,8 +6,8 @@ use super::MapError;
 
 #[derive(Debug,Error)]
 pub enum MapTypeError {
-    #[error("Map Type [{0}] neither of seed, soil, fertilizer, water, light, temperature, humidity, location")]
-    UnknownMapType(String)
+    #[error("Map Type [{0}] isn't a single word")]
+    InvalidMapType(String)
 }
 
 impl From<MapTypeError> for MapError {
@@ -1,6 +1,6 @@
 /// Mapping Error Codes
 /// Mapping -> MapError error translation
-
+///
 use std::num::ParseIntError;
 use thiserror::Error;
 use super::MapError;
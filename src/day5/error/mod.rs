@@ -1,8 +1,10 @@
 mod map;
 mod mapping;
+mod pipeline;
 
 pub use map::*;
 pub use mapping::*;
+pub use pipeline::*;
 
 use thiserror::Error;
 
@@ -13,6 +15,8 @@ pub enum MapError {
     MapTypeError(String),
     #[error("Mapping Error: [{0}]")]
     MappingError(String),
+    #[error("Pipeline Error: [{0}]")]
+    PipelineError(String),
     #[error("Map input doesn't appear to fit the desire format; cannot find <map type>-to-<map type>")]
     ParseInputFormatInvalid
 }
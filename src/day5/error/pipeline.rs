@@ -0,0 +1,23 @@
+/// Pipeline Error Codes
+/// Pipeline -> MapError error translation
+///
+use thiserror::Error;
+use super::MapError;
+
+#[derive(Debug,PartialEq,Error)]
+pub enum PipelineError {
+    #[error("Maps form a cycle: [{0}]")]
+    Cycle(String),
+    #[error("Map [{0}] is given more than once")]
+    DuplicateMap(String),
+    #[error("No map converts [{0}] values")]
+    UnknownMapType(String),
+    #[error("No maps lead from [{from}] to [{to}]")]
+    MissingLink { from: String, to: String },
+}
+
+impl From<PipelineError> for MapError {
+    fn from(err: PipelineError) -> Self {
+        MapError::PipelineError(err.to_string())
+    }
+}
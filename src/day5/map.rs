@@ -0,0 +1,385 @@
+use std::{borrow::Borrow, fmt::Display, ops::Range, rc::Rc, str::FromStr};
+use super::mapping::*;
+use super::error::*;
+
+/// Name of a category of values, e.g. `seed` or `location`, as found in the `<category>-to-<category> map:` headers
+#[derive(Debug,Hash,Eq,PartialEq,Ord,PartialOrd,Clone)]
+pub struct MapType(Rc<str>);
+
+impl MapType {
+    pub fn name(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Borrow<str> for MapType {
+    fn borrow(&self) -> &str {
+        &self.0
+    }
+}
+
+impl FromStr for MapType {
+    type Err = MapTypeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if !s.is_empty() && s.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+            Ok(MapType(s.into()))
+        } else {
+            Err(MapTypeError::InvalidMapType(s.into()))
+        }
+    }
+}
+
+impl Display for MapType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+#[derive(Debug,PartialEq)]
+pub struct Map {
+    map: MapType,
+    dest: MapType,
+    mappings: Rc<[Mapping]>
+}
+
+impl Map {
+    pub fn id(&self) -> &MapType {
+        &self.map
+    }
+    pub fn dest(&self) -> &MapType {
+        &self.dest
+    }
+    /// map leaving every value of `map_type` unchanged
+    pub fn identity(map_type: MapType) -> Map {
+        Map { map: map_type.clone(), dest: map_type, mappings: Rc::from([]) }
+    }
+    pub fn mappings(&self) -> &[Mapping] {
+        &self.mappings
+    }
+
+    /// Folds `next` into this map, giving a single map from this map's source type to `next`'s destination type
+    ///
+    /// The composed mappings are sorted by source and never overlap, hence values can be looked up with [`Map::lookup`]
+    pub fn compose(&self, next: &Map) -> Map {
+        let mut segments = self.mappings.iter().cloned().collect::<Vec<_>>();
+        segments.sort_by_key(|m| m.src_base.start);
+
+        // values in the gaps between the source ranges map onto themselves, so they're identity segments
+        let mut gaps = vec![];
+        let mut start = 0;
+        for m in segments.iter() {
+            if start < m.src_base.start { gaps.push(Mapping { src_base: start..m.src_base.start, dst_base: start }) }
+            start = start.max(m.src_base.end);
+        }
+        if start < u64::MAX { gaps.push(Mapping { src_base: start..u64::MAX, dst_base: start }) }
+        segments.extend(gaps);
+
+        let mut out = vec![];
+        for seg in segments {
+            let to_src = |n: u64| n - seg.dst_base + seg.src_base.start;
+            let mut pending = vec![seg.dst_range()];
+
+            // split this segment's image across the next map's mappings
+            for mapping in next.mappings.iter() {
+                pending = pending.into_iter()
+                    .flat_map(|rng| {
+                        let (start, end) = (rng.start.max(mapping.src_base.start), rng.end.min(mapping.src_base.end));
+                        if start < end {
+                            out.push(Mapping { src_base: to_src(start)..to_src(end), dst_base: mapping.transform(start).unwrap() });
+                        }
+                        match mapping.transform_range(&rng).1 {
+                            RangeResidue::None => vec![],
+                            RangeResidue::Single(a) => vec![a],
+                            RangeResidue::Double(a, b) => vec![a, b],
+                        }
+                    })
+                    .filter(|r| !r.is_empty())
+                    .collect();
+            }
+            // whatever the next map leaves unmapped keeps this segment's shift only
+            out.extend(pending.into_iter().map(|rng| Mapping { src_base: to_src(rng.start)..to_src(rng.end), dst_base: rng.start }));
+        }
+
+        // drop identity segments and merge neighbours shifting by the same amount
+        out.retain(|m| m.src_base.start != m.dst_base);
+        out.sort_by_key(|m| m.src_base.start);
+        let mappings = out.into_iter()
+            .fold(Vec::<Mapping>::new(), |mut acc, m| {
+                match acc.last_mut() {
+                    Some(last) if last.src_base.end == m.src_base.start && last.dst_range().end == m.dst_base =>
+                        last.src_base.end = m.src_base.end,
+                    _ => acc.push(m),
+                }
+                acc
+            });
+
+        Map { map: self.map.clone(), dest: next.dest.clone(), mappings: mappings.into() }
+    }
+
+    /// Transforms `seed` with a binary search, given the mappings are sorted by source as those of a composed map
+    pub fn lookup(&self, seed: u64) -> u64 {
+        let idx = self.mappings.partition_point(|m| m.src_base.end <= seed);
+        self.mappings.get(idx)
+            .and_then(|m| m.transform(seed))
+            .unwrap_or(seed)
+    }
+}
+
+pub trait Transform<T> {
+    fn transform(&self, seed: T) -> (T,MapType) where T: Clone;
+}
+
+/// Reverse of [`Transform`], returning every source value that maps onto the destination values, along with the source type
+pub trait Inverse<T> {
+    fn inverse(&self, dest: T) -> (T,MapType);
+}
+
+impl Transform<u64> for Map {
+    fn transform(&self, seed: u64) -> (u64,MapType) {
+        self.mappings
+            .iter()
+            .filter_map(|mapping| mapping.transform(seed))
+            .map(|seed| (seed, self.dest.clone()))
+            .next()
+            .unwrap_or( (seed, self.dest.clone()))
+    }
+}
+
+impl Transform<Rc<[Range<u64>]>> for Map {
+    fn transform(&self, seeds: Rc<[Range<u64>]>) -> (Rc<[Range<u64>]>,MapType) {
+        let mut flip: Vec<Range<u64>> = seeds.as_ref().into();
+        let mut flop = Vec::with_capacity(seeds.len()*2);
+        let mut out = Vec::with_capacity(seeds.len());
+
+        for mapping in self.mappings.iter() {
+            while let Some(rng) = flip.pop() {
+                // map input range into mapped and residual range(s)
+                let (mapped, residual) = mapping.transform_range(&rng);
+                // push mapped range to the output
+                if let Some(r) = mapped { out.push(r) };
+                // push residual to the queue for processing by subsequent mappings
+                match residual {
+                    RangeResidue::Single(a) => flop.push(a),
+                    RangeResidue::Double(a, b) => flop.extend([a,b]),
+                    _ => (),
+                }
+            }
+            // flip/flop the pointers to the queues' memory allocation:
+            // one is now empty and the other has the ranges for processing by the next mapping
+            // so we avoid temporary vector and subsequenly heap allocation
+            std::mem::swap::<Vec<Range<u64>>>(&mut flip, &mut flop);
+            // println!("{:?}",(self.map, mapping,&queue1));
+        }
+        // add remaining residual ranges following the processing of all mappings
+        flip.extend(out);
+
+        (flip.into(), self.dest.clone())
+    }
+}
+
+impl Inverse<Rc<[u64]>> for Map {
+    fn inverse(&self, dest: Rc<[u64]>) -> (Rc<[u64]>,MapType) {
+        let mut out = dest.iter()
+            .flat_map(|&d| {
+                // a value outside every source range maps onto itself
+                let unmapped = self.mappings.iter().all(|m| !m.src_base.contains(&d));
+                self.mappings.iter()
+                    .filter_map(move |m| m.inverse(d))
+                    .chain(unmapped.then_some(d))
+            })
+            .collect::<Vec<_>>();
+        out.sort();
+        out.dedup();
+
+        (out.into(), self.map.clone())
+    }
+}
+
+impl Inverse<Rc<[Range<u64>]>> for Map {
+    fn inverse(&self, dest: Rc<[Range<u64>]>) -> (Rc<[Range<u64>]>,MapType) {
+        let mut out = dest.iter()
+            .flat_map(|rng| self.mappings.iter().filter_map(|m| m.inverse_range(rng)))
+            .collect::<Vec<_>>();
+
+        // the parts of the ranges outside every source range map onto themselves
+        let mut unmapped = dest.iter().filter(|r| !r.is_empty()).cloned().collect::<Vec<_>>();
+        for mapping in self.mappings.iter() {
+            unmapped = unmapped.into_iter()
+                .flat_map(|rng| match mapping.transform_range(&rng).1 {
+                    RangeResidue::None => vec![],
+                    RangeResidue::Single(a) => vec![a],
+                    RangeResidue::Double(a, b) => vec![a, b],
+                })
+                .filter(|r| !r.is_empty())
+                .collect();
+        }
+        out.extend(unmapped);
+
+        (merge_ranges(out).into(), self.map.clone())
+    }
+}
+
+/// sorts the ranges and merges those overlapping or adjacent
+pub fn merge_ranges(mut ranges: Vec<Range<u64>>) -> Vec<Range<u64>> {
+    ranges.sort_by_key(|r| r.start);
+    ranges.into_iter()
+        .fold(Vec::new(), |mut out: Vec<Range<u64>>, rng| {
+            match out.last_mut() {
+                Some(last) if rng.start <= last.end => last.end = last.end.max(rng.end),
+                _ => out.push(rng),
+            }
+            out
+        })
+}
+
+impl Display for Map {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-to-{} map:", self.map, self.dest)?;
+        self.mappings.iter().try_for_each(|m| write!(f, "\n{m}"))
+    }
+}
+
+impl FromStr for Map {
+    type Err = MapError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut maps = s
+            .split("\n\n")
+            .next()
+            .ok_or(MapError::ParseInputFormatInvalid)?
+            .lines();
+
+        let mut map_type = maps
+            .next().ok_or(MapError::MapTypeError(s.to_string()))?
+            .split_whitespace()
+            .next().ok_or(MapError::MapTypeError(s.to_string()))?
+            .split("-to-")
+            .map(|map| map.parse::<MapType>());
+
+        Ok(Map {
+            map: map_type.next().ok_or(MapError::ParseInputFormatInvalid)??,
+            dest: map_type.next().ok_or(MapError::ParseInputFormatInvalid)??,
+            mappings: maps
+                .map(|m| m.parse::<Mapping>())
+                .collect::<Result<Rc<[_]>,MappingError>>()?
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::day5::Seeds;
+
+    fn map_type(name: &str) -> MapType {
+        name.parse().expect("Ops!")
+    }
+
+    #[test]
+    fn test_map_transform_ranges() {
+        let input = std::fs::read_to_string("./src/bin/day5/sample.txt").expect("Ops!");
+        let mut split = input.split("\n\n");
+        let seeds = split.next().unwrap().parse::<Seeds>().expect("Ops!");
+        let map = split.next().unwrap().parse::<Map>().expect("Ops!");
+
+        println!("{:?}",
+            map.transform(seeds.get_ranges())
+        );
+    }
+
+    #[test]
+    fn test_map_transform() {
+        let input = std::fs::read_to_string("./src/bin/day5/sample.txt").expect("Ops!");
+        let mut split = input.split("\n\n");
+        let seeds = split.next().unwrap().parse::<Seeds>().expect("Ops!");
+        let map = split.next().unwrap().parse::<Map>().expect("Ops!");
+
+        seeds.iter()
+            .inspect(|seed| print!("Input: {seed}"))
+            .map(|&seed| map.transform(seed))
+            .for_each(|d| println!(" -> {:?}",d));
+
+        assert_eq!(
+            seeds.iter().map(|&seed| map.transform(seed)).collect::<Vec<_>>(),
+            [(81, map_type("soil")), (14, map_type("soil")), (57, map_type("soil")),(13, map_type("soil"))]
+        )
+    }
+
+    #[test]
+    fn test_map_inverse() {
+        let input = std::fs::read_to_string("./src/bin/day5/sample.txt").expect("Ops!");
+        let map = input.split("\n\n").nth(1).unwrap().parse::<Map>().expect("Ops!");
+
+        // soil 50 comes from seed 98, while seed 14 is left unmapped
+        assert_eq!(map.inverse(Rc::<[u64]>::from([81, 50, 14, 52])), (Rc::from([14, 50, 79, 98]), map_type("seed")));
+        assert_eq!(
+            map.inverse(Rc::<[Range<u64>]>::from([0..55, 81..95])),
+            (Rc::from([0..53, 79..93, 98..100].as_slice()), map_type("seed"))
+        );
+    }
+
+    #[test]
+    fn test_map_compose() {
+        let input = std::fs::read_to_string("./src/bin/day5/sample.txt").expect("Ops!");
+        let mut split = input.split("\n\n").skip(1);
+        let soil = split.next().unwrap().parse::<Map>().expect("Ops!");
+        let fertilizer = split.next().unwrap().parse::<Map>().expect("Ops!");
+
+        let composed = soil.compose(&fertilizer);
+        assert_eq!((composed.id().name(), composed.dest().name()), ("seed", "fertilizer"));
+        for seed in 0..120 {
+            let expected = fertilizer.transform(soil.transform(seed).0).0;
+            assert_eq!(composed.transform(seed).0, expected);
+            assert_eq!(composed.lookup(seed), expected);
+        }
+
+        println!("{composed}");
+        assert_eq!(composed.to_string().parse::<Map>().expect("Ops!"), composed);
+        assert_eq!(soil.to_string(), "seed-to-soil map:\n50 98 2\n52 50 48");
+    }
+
+    #[test]
+    fn test_parse_map() {
+        let data = std::fs::read_to_string("./src/bin/day5/sample.txt").expect("Ops!");
+        let input = data.split("\n\n").nth(1).unwrap();
+
+        let map = input.parse::<Map>().expect("Map::Ops!");
+        println!("{:?}",map);
+        assert_eq!(
+            map,
+            Map { map: map_type("seed"), dest: map_type("soil"),
+                    mappings: vec![
+                        Mapping { src_base: 98..100, dst_base: 50 },
+                        Mapping { src_base: 50..98, dst_base: 52 }
+                    ].into()
+                }
+        )
+    }
+
+    #[test]
+    fn test_parse_map_erros() {
+        let input = [
+        ("so.il-to-fertilizer map:\n39 0 15",
+            MapError::MapTypeError("Map Type [so.il] isn't a single word".to_string())),
+        ("fertilizer-to-water map:\n57 7 A",
+            MapError::MappingError("Invalid Mapping value: [invalid digit found in string in \"57 7 A\"]".to_string())),
+        ("fertilizer-to-water map:\n57 4",
+            MapError::MappingError("Missing Mapping value: [57 4]".to_string())),
+        ("fertilizer-too-water map:\n57 4 9",
+            MapError::MapTypeError("Map Type [fertilizer-too-water] isn't a single word".to_string())),
+        ("fertilizer water map:\n57 4",
+            MapError::ParseInputFormatInvalid)
+        ];
+
+        for (test,err) in input {
+            match test.parse::<Map>() {
+                Ok(_) => panic!("Test case [{test:?}] should not succeed!"),
+                Err(e) => {
+                    println!("Received [{e:?}], Expected [{err:?}] in {test:}");
+                    assert_eq!(e, err)
+                }
+            }
+        }
+    }
+}
@@ -1,15 +1,15 @@
-use std::{ops::Range, str::FromStr};
+use std::{fmt::Display, ops::Range, str::FromStr};
 use super::error::*;
 
 #[derive(Debug,PartialEq)]
-pub(crate) enum RangeResidue {
+pub enum RangeResidue {
     None,
     Single(Range<u64>),
     Double(Range<u64>,Range<u64>)
 }
 
-#[derive(Debug,PartialEq)]
-pub(crate) struct Mapping {
+#[derive(Debug,PartialEq,Clone)]
+pub struct Mapping {
     pub src_base: Range<u64>, // 98 (98,99)
     pub dst_base: u64, // 52
 }
@@ -17,10 +17,19 @@ pub(crate) struct Mapping {
 impl Mapping {
     #[inline]
     fn shift(&self, n:u64) ->u64 {
-        self.dst_base + n - self.src_base.start
+        self.dst_base + (n - self.src_base.start)
+    }
+
+    #[inline]
+    fn unshift(&self, n:u64) ->u64 {
+        self.src_base.start + (n - self.dst_base)
     }
 
-    pub(crate) fn transform(&self, seed: u64) -> Option<u64> {
+    pub fn dst_range(&self) -> Range<u64> {
+        self.dst_base..self.dst_base + (self.src_base.end - self.src_base.start)
+    }
+
+    pub fn transform(&self, seed: u64) -> Option<u64> {
         if self.src_base.contains(&seed) {
             Some(self.shift(seed))
         } else {
@@ -28,7 +37,7 @@ impl Mapping {
         }
     }
 
-    pub(crate) fn transform_range(&self, rng: &Range<u64>) -> (Option<Range<u64>>,RangeResidue) {
+    pub fn transform_range(&self, rng: &Range<u64>) -> (Option<Range<u64>>,RangeResidue) {
         let src = &self.src_base;
         match (src.contains(&rng.start), src.contains(&(rng.end-1))) {
             (true, true) =>
@@ -52,6 +61,32 @@ impl Mapping {
             }
         }
     }
+
+    /// source value that maps onto `dest`, if `dest` falls within the destination range
+    pub fn inverse(&self, dest: u64) -> Option<u64> {
+        if self.dst_range().contains(&dest) {
+            Some(self.unshift(dest))
+        } else {
+            None
+        }
+    }
+
+    /// source range that maps onto the part of `rng` overlapping the destination range
+    pub fn inverse_range(&self, rng: &Range<u64>) -> Option<Range<u64>> {
+        let dst = self.dst_range();
+        let (start, end) = (rng.start.max(dst.start), rng.end.min(dst.end));
+        if start < end {
+            Some(self.unshift(start)..self.unshift(end))
+        } else {
+            None
+        }
+    }
+}
+
+impl Display for Mapping {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {} {}", self.dst_base, self.src_base.start, self.src_base.end - self.src_base.start)
+    }
 }
 
 impl FromStr for Mapping {
@@ -120,6 +155,29 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_mapping_inverse() {
+        let data = [
+            ("50 98 2", 51, Some(99), 40..60, Some(98..100)),
+            ("52 50 48", 81, Some(79), 81..100, Some(79..98)),
+            ("0 15 37", 37, None, 30..45, Some(45..52)),
+            ("37 52 2", 36, None, 20..37, None),
+            ("39 0 15", 53, Some(14), 0..100, Some(0..15))
+        ];
+
+        for (inp, dest, seed, rng, out) in data {
+            let mapping = inp.parse::<Mapping>().expect("Ops");
+            assert_eq!(mapping.inverse(dest), seed);
+            assert_eq!(mapping.inverse_range(&rng), out);
+            // round-trip
+            if let Some(seed) = seed { assert_eq!(mapping.transform(seed), Some(dest)) }
+            if let Some(out) = out {
+                let (mapped, _) = mapping.transform_range(&out);
+                assert!(mapped.is_some_and(|m| rng.start <= m.start && m.end <= rng.end))
+            }
+        }
+    }
+
     #[test]
     fn test_mapping_parse_error() {
         let data = [
@@ -0,0 +1,38 @@
+pub mod map;
+pub mod mapping;
+pub mod pipeline;
+pub mod error;
+
+use pipeline::{ Run, Pipeline, Seeds, SEED, LOCATION};
+use crate::Solution;
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+    type Input = (Seeds, Pipeline);
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> (Seeds, Pipeline) {
+        (
+            input.parse::<Seeds>().unwrap_or_else(|e| panic!("{e}")),
+            input.parse::<Pipeline>().unwrap_or_else(|e| panic!("{e:?}"))
+        )
+    }
+    fn part1((seeds, pipeline): &(Seeds, Pipeline)) -> u64 {
+        seeds.iter()
+            .map(|&seed|
+                pipeline.run(seed, SEED, LOCATION).unwrap_or_else(|e| panic!("{e}"))
+            )
+            .min()
+            .unwrap()
+    }
+    fn part2((seeds, pipeline): &(Seeds, Pipeline)) -> u64 {
+        pipeline.run(seeds.get_ranges(), SEED, LOCATION).unwrap_or_else(|e| panic!("{e}"))
+            .iter()
+            .min_by_key(|r| r.start)
+            .unwrap()
+            .start
+    }
+}
@@ -0,0 +1,373 @@
+use std::{collections::{HashMap, HashSet, VecDeque}, num::ParseIntError, ops::Range, rc::Rc, str::FromStr};
+use super::error::*;
+use super::map::{Transform,Inverse,MapType,Map};
+
+pub struct Seeds(Rc<[u64]>);
+
+impl Seeds {
+    pub fn get_ranges(&self) -> Rc<[Range<u64>]> {
+        self.0.chunks(2)
+            .map(|r| r[0]..r[0]+r[1])
+            .collect::<Rc<[_]>>()
+    }
+
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item=&u64> {
+        self.0.iter()
+    }
+}
+
+impl FromStr for Seeds {
+    type Err = ParseIntError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Ok(Seeds(
+            input.split("\n\n")
+            .next().unwrap()
+            .split(':')
+            .next_back().unwrap()
+            .split_whitespace()
+            .map(|num| num.trim().parse::<u64>())
+            .collect::<Result<Rc<[_]>,ParseIntError>>()?
+        ))
+    }
+}
+
+/// Categories the puzzle converts from and to
+pub const SEED: &str = "seed";
+pub const LOCATION: &str = "location";
+
+/// Directed graph of categories, with an edge for every `<category>-to-<category>` map
+pub struct Pipeline {
+    // maps keyed by the category they convert from
+    maps: HashMap<MapType,Vec<Map>>
+}
+
+/// Converts values of category `from` into values of category `to`, through the maps found by [`Pipeline::route`]
+pub trait Run<T> {
+    fn run(&self, seed: T, from: &str, to: &str) -> Result<T, PipelineError>;
+}
+
+impl Run<u64> for Pipeline {
+    fn run(&self, seed: u64, from: &str, to: &str) -> Result<u64, PipelineError> {
+        Ok(
+            self.route(from, to)?
+                .into_iter()
+                .fold(seed, |out, map| map.transform(out).0)
+        )
+    }
+}
+
+impl Run<Rc<[Range<u64>]>> for Pipeline {
+    fn run(&self, seeds: Rc<[Range<u64>]>, from: &str, to: &str) -> Result<Rc<[Range<u64>]>, PipelineError> {
+        Ok(
+            self.route(from, to)?
+                .into_iter()
+                .fold(seeds, |out, map| map.transform(out).0)
+        )
+    }
+}
+
+/// Reverse of [`Run`], walking the maps backwards from values of category `from`, e.g. location, to the values
+/// of category `to`, e.g. seed, they originate from
+pub trait RunInverse<T> {
+    fn run_inverse(&self, dest: T, from: &str, to: &str) -> Result<T, PipelineError>;
+}
+
+impl RunInverse<Rc<[u64]>> for Pipeline {
+    fn run_inverse(&self, dest: Rc<[u64]>, from: &str, to: &str) -> Result<Rc<[u64]>, PipelineError> {
+        Ok(
+            self.route(to, from)?
+                .into_iter()
+                .rev()
+                .fold(dest, |out, map| map.inverse(out).0)
+        )
+    }
+}
+
+impl RunInverse<Rc<[Range<u64>]>> for Pipeline {
+    fn run_inverse(&self, dest: Rc<[Range<u64>]>, from: &str, to: &str) -> Result<Rc<[Range<u64>]>, PipelineError> {
+        Ok(
+            self.route(to, from)?
+                .into_iter()
+                .rev()
+                .fold(dest, |out, map| map.inverse(out).0)
+        )
+    }
+}
+
+impl Pipeline {
+    /// The category named `name`, if any map converts from or to it
+    pub fn map_type(&self, name: &str) -> Option<&MapType> {
+        self.maps.get_key_value(name).map(|(k, _)| k)
+            .or_else(|| self.maps.values().flatten().map(|m| m.dest()).find(|d| d.name() == name))
+    }
+
+    /// Maps converting category `from` into category `to`, in order of application, along the fewest stages
+    pub fn route<'a>(&'a self, from: &'a str, to: &'a str) -> Result<Vec<&'a Map>, PipelineError> {
+        for name in [from, to] {
+            if self.map_type(name).is_none() { return Err(PipelineError::UnknownMapType(name.into())) }
+        }
+
+        // breadth-first search, remembering the map that first reached each category
+        let mut reached_by = HashMap::<&str, &Map>::new();
+        let mut queue = VecDeque::from([from]);
+        while let Some(category) = queue.pop_front() {
+            if category == to { break }
+            for map in self.maps.get(category).into_iter().flatten() {
+                let next = map.dest().name();
+                if next != from && !reached_by.contains_key(next) {
+                    reached_by.insert(next, map);
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        let mut route = vec![];
+        let mut category = to;
+        while category != from {
+            let map = reached_by.get(category)
+                .ok_or_else(|| PipelineError::MissingLink { from: from.into(), to: to.into() })?;
+            route.push(*map);
+            category = map.id().name();
+        }
+        route.reverse();
+        Ok(route)
+    }
+
+    /// Folds the maps converting `from` into `to` into a single map, e.g. from seed straight to location
+    pub fn compose(&self, from: &str, to: &str) -> Result<Map, PipelineError> {
+        let route = self.route(from, to)?;
+        let start = self.map_type(from).expect("route() checks the category exists").clone();
+
+        Ok(
+            route.into_iter()
+                .fold(Map::identity(start), |composed, map| composed.compose(map))
+        )
+    }
+
+    /// A chain of categories that leads back to its start, if any, e.g. `a -> b -> a`
+    fn find_cycle(&self) -> Option<String> {
+        // depth first search, where `on_path` holds the categories being explored and `done` those fully explored
+        fn visit<'a>(
+            pipeline: &'a Pipeline,
+            category: &'a MapType,
+            on_path: &mut Vec<&'a MapType>,
+            done: &mut HashSet<&'a MapType>
+        ) -> Option<String> {
+            if let Some(pos) = on_path.iter().position(|&c| c == category) {
+                return Some(
+                    on_path[pos..].iter()
+                        .chain([&category])
+                        .map(|c| c.name())
+                        .collect::<Vec<_>>()
+                        .join(" -> ")
+                )
+            }
+            if done.contains(category) { return None }
+
+            on_path.push(category);
+            let cycle = pipeline.maps.get(category).into_iter().flatten()
+                .find_map(|map| visit(pipeline, map.dest(), on_path, done));
+            on_path.pop();
+            done.insert(category);
+            cycle
+        }
+
+        let mut sources = self.maps.keys().collect::<Vec<_>>();
+        sources.sort();
+        let mut done = HashSet::new();
+        sources.into_iter()
+            .find_map(|category| visit(self, category, &mut vec![], &mut done))
+    }
+}
+
+impl FromStr for Pipeline {
+    type Err = MapError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let split = input.split("\n\n").skip(1);
+        let mut maps = HashMap::<MapType,Vec<Map>>::new();
+
+        for map in split.map(|m| m.parse::<Map>()) {
+            let map = map?;
+            let same_source = maps.entry(map.id().clone()).or_default();
+            if same_source.iter().any(|m| m.dest() == map.dest()) {
+                return Err(PipelineError::DuplicateMap(format!("{}-to-{}", map.id(), map.dest())).into())
+            }
+            same_source.push(map);
+        }
+
+        let pipeline = Pipeline { maps };
+        match pipeline.find_cycle() {
+            Some(cycle) => Err(PipelineError::Cycle(cycle).into()),
+            None => Ok(pipeline)
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod test_pipeline {
+    use super::*;
+
+    #[test]
+    fn test_pipeline_ranges() {
+        let input = std::fs::read_to_string("./src/bin/day5/sample.txt").expect("Ops!");
+        let seeds = input.parse::<Seeds>().map_err(|e| panic!("{e}")).unwrap();
+        let pipeline = input.parse::<Pipeline>().map_err(|e| panic!("{e:?}")).unwrap();
+
+        let ranges = pipeline.run(seeds.get_ranges(), SEED, LOCATION).expect("Ops!");
+        let min = ranges
+            .iter()
+            .min_by_key(|d| d.start )
+            .unwrap();
+
+        println!("{:?}",min);
+        assert_eq!(min.start,46);
+    }
+
+    #[test]
+    fn test_min_location() {
+        let input = std::fs::read_to_string("./src/bin/day5/sample.txt").expect("Ops!");
+        let seeds = input.parse::<Seeds>().expect("Ops!");
+        let pipeline = input.parse::<Pipeline>().expect("Ops!");
+
+        let min = seeds.0.iter()
+            .map(|&seed|
+                pipeline.run(seed, SEED, LOCATION).expect("Ops!")
+            )
+            .min();
+
+        assert_eq!( Some(35),min);
+    }
+
+    #[test]
+    fn test_pipeline_inverse() {
+        let input = std::fs::read_to_string("./src/bin/day5/sample.txt").expect("Ops!");
+        let seeds = input.parse::<Seeds>().expect("Ops!");
+        let pipeline = input.parse::<Pipeline>().expect("Ops!");
+
+        // every seed reaching locations 82 & 35 maps back onto them
+        let found = pipeline.run_inverse(Rc::<[u64]>::from([82, 35]), LOCATION, SEED).expect("Ops!");
+        assert!(found.contains(&79) && found.contains(&13));
+        assert!(found.iter().all(|&seed| [82, 35].contains(&pipeline.run(seed, SEED, LOCATION).expect("Ops!"))));
+
+        // seeds producing locations below 46 & 47 respectively
+        let overlaps = |locations: Range<u64>| {
+            pipeline.run_inverse(Rc::<[Range<u64>]>::from([locations]), LOCATION, SEED).expect("Ops!").iter()
+                .flat_map(|r| seeds.get_ranges().iter()
+                    .map(|s| r.start.max(s.start)..r.end.min(s.end))
+                    .filter(|o| !o.is_empty())
+                    .collect::<Vec<_>>()
+                )
+                .collect::<Vec<_>>()
+        };
+        assert!(overlaps(0..46).is_empty());
+        // seed 82 is the only one reaching location 46
+        assert_eq!(overlaps(0..47).iter().map(|r| (r.start, r.end)).collect::<Vec<_>>(), [(82, 83)]);
+
+        // round-trip: the seed ranges reaching locations 50..70 map back inside 50..70
+        let ranges = pipeline.run_inverse(std::iter::once(50..70).collect::<Rc<[_]>>(), LOCATION, SEED).expect("Ops!");
+        assert_eq!(ranges.iter().map(|r| r.end - r.start).sum::<u64>(), 20);
+        assert!(pipeline.run(ranges, SEED, LOCATION).expect("Ops!").iter().all(|r| 50 <= r.start && r.end <= 70));
+    }
+
+    #[test]
+    fn test_pipeline_compose() {
+        let input = std::fs::read_to_string("./src/bin/day5/sample.txt").expect("Ops!");
+        let seeds = input.parse::<Seeds>().expect("Ops!");
+        let pipeline = input.parse::<Pipeline>().expect("Ops!");
+
+        let map = pipeline.compose(SEED, LOCATION).expect("Ops!");
+        println!("{map}");
+        assert_eq!((map.id().name(), map.dest().name()), (SEED, LOCATION));
+        assert!(map.mappings().windows(2).all(|w| w[0].src_base.end <= w[1].src_base.start));
+
+        for seed in 0..120 {
+            assert_eq!(map.lookup(seed), pipeline.run(seed, SEED, LOCATION).expect("Ops!"));
+        }
+        assert_eq!(seeds.iter().map(|&s| map.lookup(s)).min(), Some(35));
+        assert_eq!(map.transform(seeds.get_ranges()).0.iter().map(|r| r.start).min(), Some(46));
+
+        assert_eq!(
+            pipeline.compose(SEED, "water").expect("Ops!").to_string(),
+            pipeline.compose(SEED, "fertilizer").expect("Ops!").compose(&pipeline.compose("fertilizer", "water").expect("Ops!")).to_string()
+        );
+        assert!(pipeline.compose(LOCATION, SEED).is_err());
+    }
+
+    #[test]
+    fn test_pipeline_route() {
+        let input = std::fs::read_to_string("./src/bin/day5/sample.txt").expect("Ops!");
+        let pipeline = input.parse::<Pipeline>().expect("Ops!");
+
+        let route = |from, to| pipeline.route(from, to)
+            .map(|maps| maps.iter().map(|m| m.to_string().lines().next().unwrap().to_string()).collect::<Vec<_>>());
+        assert_eq!(route("water", "temperature"), Ok(vec!["water-to-light map:".into(), "light-to-temperature map:".into()]));
+        assert_eq!(route("light", "light"), Ok(vec![]));
+        assert_eq!(route("light", "soil"), Err(PipelineError::MissingLink { from: "light".into(), to: "soil".into() }));
+        assert_eq!(route("seed", "sand"), Err(PipelineError::UnknownMapType("sand".into())));
+
+        // soil 81 is fertilizer 81, water 81, light 74, temperature 78
+        assert_eq!(pipeline.run(81, "soil", "temperature"), Ok(78));
+        assert_eq!(pipeline.run_inverse(Rc::<[u64]>::from([74]), "light", "soil"), Ok(Rc::from([81])));
+    }
+
+    #[test]
+    fn test_pipeline_graph() {
+        // seeds can reach location either directly or through water; the fewest stages win
+        let input = "seeds: 1\n\nseed-to-water map:\n10 0 5\n\nwater-to-location map:\n100 10 5\n\nseed-to-location map:\n50 0 5";
+        let pipeline = input.parse::<Pipeline>().expect("Ops!");
+        assert_eq!(pipeline.run(2, SEED, LOCATION), Ok(52));
+        assert_eq!(pipeline.run(2, SEED, "water"), Ok(12));
+        assert_eq!(pipeline.run(12, "water", LOCATION), Ok(102));
+
+        let data = [
+            ("seeds: 1\n\na-to-b map:\n1 2 3\n\nb-to-c map:\n1 2 3\n\nc-to-a map:\n1 2 3",
+                MapError::PipelineError("Maps form a cycle: [a -> b -> c -> a]".into())),
+            ("seeds: 1\n\na-to-a map:\n1 2 3",
+                MapError::PipelineError("Maps form a cycle: [a -> a]".into())),
+            ("seeds: 1\n\na-to-b map:\n1 2 3\n\na-to-b map:\n4 5 6",
+                MapError::PipelineError("Map [a-to-b] is given more than once".into())),
+        ];
+        for (test, err) in data {
+            match test.parse::<Pipeline>() {
+                Ok(_) => panic!("Test case [{test:?}] should not succeed!"),
+                Err(e) => assert_eq!(e, err)
+            }
+        }
+    }
+
+    #[test]
+    fn test_pipeline() {
+        let input = std::fs::read_to_string("./src/bin/day5/sample.txt").expect("Ops!");
+        let seeds = input.parse::<Seeds>().expect("Ops!");
+        let pipeline = input.parse::<Pipeline>().expect("Ops!");
+
+        assert_eq!(82, pipeline.run(seeds.0[0], SEED, LOCATION).expect("Ops!"));
+    }
+}
+
+#[cfg(test)]
+mod test_seeds {
+    use super::*;
+
+    #[test]
+    fn test_ranges() {
+        let input = std::fs::read_to_string("./src/bin/day5/sample.txt").expect("Ops!");
+        let seeds = input.parse::<Seeds>().expect("Ops!");
+        let ranges = seeds.get_ranges();
+        assert_eq!(
+            ranges,
+            [79..93, 55..68].into()
+        )
+    }
+
+    #[test]
+    fn test_parse_seeds() {
+        let input = std::fs::read_to_string("./src/bin/day5/sample.txt").expect("Ops!");
+        let seeds = input.parse::<Seeds>().expect("Ops!");
+        assert_eq!(seeds.0,[79_u64,14,55,13].into());
+    }
+}
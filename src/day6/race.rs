@@ -1,9 +1,9 @@
 use std::num::ParseIntError;
 use std::str::FromStr;
 
-pub(crate) struct Boat;
+pub struct Boat;
 impl Boat {
-    pub(crate) fn distance_travelled(charge: u64, duration: u64) -> u64 {
+    pub fn distance_travelled(charge: u64, duration: u64) -> u64 {
         // what is the min charge to win the race?
         // (duration - x)*x = Winning_distance
         // => x^2 - duration * x + Winning_distance = 0
@@ -12,18 +12,18 @@ impl Boat {
 }
 
 #[derive(Debug,PartialEq)]
-pub(crate) struct Race {
-    pub(crate) duration: u64,
-    pub(crate) record: u64
+pub struct Race {
+    pub duration: u64,
+    pub record: u64
 }
 
 impl Race {
-    pub(crate) fn _trial_charge_times(&self) -> impl Iterator<Item=(u64, u64)> + '_ {
+    pub fn _trial_charge_times(&self) -> impl Iterator<Item=(u64, u64)> + '_ {
         (0..=self.duration).map(|charge|
             ( charge, Boat::distance_travelled(charge,self.duration) )
         )
     }
-    pub(crate) fn _winning_charge_times(&self) -> impl Iterator<Item=(u64, u64)> + '_ {
+    pub fn _winning_charge_times(&self) -> impl Iterator<Item=(u64, u64)> + '_ {
         self._trial_charge_times().filter(|&(_,dist)| dist > self.record)
     }
 
@@ -36,20 +36,20 @@ impl Race {
         output
     }
 
-    pub(crate) fn find_lower_winning_charge(&self) -> u64 {
+    pub fn find_lower_winning_charge(&self) -> u64 {
         let charge = (self.duration - u64::isqrt(u64::pow(self.duration,2) - 4*self.record)) / 2;
         self.find_winning_charge_between( (charge - 1) ..= (charge + 1) )
     }
 
-    pub(crate) fn find_upper_winning_charge(&self) -> u64 {
+    pub fn find_upper_winning_charge(&self) -> u64 {
         let charge = (self.duration + u64::isqrt(u64::pow(self.duration,2) - 4*self.record)) / 2;
         self.find_winning_charge_between( ((charge - 1) ..= (charge + 1)).rev() )
     }
 
-    pub(crate) fn parse_races(input: &str) -> impl Iterator<Item=Race> + '_ {
+    pub fn parse_races(input: &str) -> impl Iterator<Item=Race> + '_ {
         let mut split = input.split('\n');
-        let time = split.next().unwrap().split(':').last().unwrap().split_ascii_whitespace();
-        let dist = split.next().unwrap().split(':').last().unwrap().split_ascii_whitespace();
+        let time = split.next().unwrap().split(':').next_back().unwrap().split_ascii_whitespace();
+        let dist = split.next().unwrap().split(':').next_back().unwrap().split_ascii_whitespace();
         time.zip(dist)
             .map(|(charge,dist)|
                 (
@@ -58,11 +58,11 @@ impl Race {
                 ).into()
             )
     }
-    pub(crate) fn parse_whole_numbers(input: &str) -> Result<Race,ParseIntError> {
+    pub fn parse_whole_numbers(input: &str) -> Result<Race,ParseIntError> {
         let mut split = input.split('\n');
-        let time = split.next().unwrap().split(':').last().unwrap()
+        let time = split.next().unwrap().split(':').next_back().unwrap()
             .split_ascii_whitespace().flat_map(|c| c.chars()).collect::<String>();
-        let dist = split.next().unwrap().split(':').last().unwrap()
+        let dist = split.next().unwrap().split(':').next_back().unwrap()
             .split_ascii_whitespace().flat_map(|c| c.chars()).collect::<String>();
 
         Ok(Race {
@@ -3,7 +3,7 @@ use std::collections::HashMap;
 use std::fmt::{Debug, Formatter};
 
 #[derive(Debug,Ord, PartialOrd, Eq, PartialEq,Copy, Clone)]
-pub(crate) enum HandType {
+pub enum HandType {
     HighCard = 0,
     OnePair,
     TwoPair,
@@ -13,22 +13,22 @@ pub(crate) enum HandType {
     FiveOfAKind,
 }
 
-pub(crate) struct Hand {
-    pub(crate) layout: String,
-    pub(crate) hands_type: HandType,
-    pub(crate) ord_layout: String,
-    pub(crate) cards: std::rc::Rc<[(char,u8)]>,
+pub struct Hand {
+    pub layout: String,
+    pub hands_type: HandType,
+    pub ord_layout: String,
+    pub cards: std::rc::Rc<[(char,u8)]>,
     joker_pos: Option<usize>
 }
 impl Hand {
-    pub(crate) fn get_type(&self) -> HandType {
+    pub fn get_type(&self) -> HandType {
         let mut unique_cards = self.cards.len() as u32;
         let mut freq = self.cards[0].1;
 
         // if we have joker position && and is not a 'JJJJJ' case
-        if self.joker_pos.is_some() && unique_cards > 1 {
+        if let Some(joker_pos) = self.joker_pos.filter(|_| unique_cards > 1) {
             unique_cards -= 1;
-            freq += self.cards[self.joker_pos.unwrap()].1;
+            freq += self.cards[joker_pos].1;
         }
 
         match unique_cards {
@@ -41,7 +41,7 @@ impl Hand {
             _ => HandType::HighCard
         }
     }
-    pub(crate) fn parse(input: &str, card_order: [char; 13], joker:Option<char>) -> Hand {
+    pub fn parse(input: &str, card_order: [char; 13], joker:Option<char>) -> Hand {
 
         let ord_card = card_order.iter()
             .zip([ '2', '3', '4', '5', '6', '7', '8', '9', 'A', 'B', 'C', 'D', 'E' ])
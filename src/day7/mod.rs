@@ -0,0 +1,141 @@
+pub mod hand;
+
+use std::rc::Rc;
+use hand::Hand;
+use crate::Solution;
+
+static CAMEL_ORDER_PART1: [char; 13] = [ '2', '3', '4', '5', '6', '7', '8', '9', 'T', 'J', 'Q', 'K', 'A' ];
+static CAMEL_ORDER_PART2: [char; 13] = [ 'J', '2', '3', '4', '5', '6', '7', '8', '9', 'T', 'Q', 'K', 'A' ];
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+    type Input = Rc<[(String, u32)]>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Self::Input {
+        input.lines()
+            .map(|line|{
+                let mut split = line.split_ascii_whitespace();
+                (
+                    split.next().expect("Ops!").to_string(),
+                    split.next().unwrap().parse::<u32>().expect("Ops!")
+                )
+            })
+            .collect::<Rc<_>>()
+    }
+    fn part1(bids: &Self::Input) -> u32 {
+        total_winnings(bids, CAMEL_ORDER_PART1, None)
+    }
+    fn part2(bids: &Self::Input) -> u32 {
+        total_winnings(bids, CAMEL_ORDER_PART2, Some('J'))
+    }
+}
+
+fn total_winnings(bids: &[(String, u32)], camel_order: [char; 13], joker: Option<char>) -> u32 {
+    let mut hands = bids.iter()
+        .map(|(hand, bid)| (Hand::parse(hand, camel_order, joker), *bid))
+        .collect::<Vec<_>>();
+
+    hands.sort();
+    hands.iter()
+        .enumerate()
+        .map(|(i,(_,bid))| (i as u32+1) * bid )
+        .sum::<u32>()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use hand::HandType;
+
+    #[test]
+    fn test_card_ordering_joker() {
+        let (input, _) = parse_input(INPUT);
+        let mut hands = input
+            .into_iter()
+            .map(|line| Hand::parse(line,CAMEL_ORDER_PART2,Some('J')))
+            .collect::<Vec<_>>();
+
+        hands.sort();
+        assert_eq!(
+            vec!["32T3K", "KK677", "JJ958", "T55J5", "QQQJA", "KTJJT"],
+            order(&hands)
+        )
+    }
+    #[test]
+    fn test_card_ordering() {
+        let (input, _) = parse_input(INPUT);
+        let mut hands = input
+            .into_iter()
+            .map(|line| Hand::parse(line,CAMEL_ORDER_PART1,None))
+            .collect::<Vec<_>>();
+
+        hands.sort();
+        assert_eq!(
+            vec!["32T3K", "JJ958", "KTJJT", "KK677", "T55J5", "QQQJA"],
+            order(&hands)
+        )
+    }
+    #[test]
+    fn test_parse_joker_classify() {
+        use HandType as H;
+
+        let (input, _) = parse_input(INPUT);
+        let hands = input
+            .into_iter()
+            .map(|line| Hand::parse(line,CAMEL_ORDER_PART2,Some('J')))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            vec![H::OnePair, H::FourOfAKind, H::TwoPair, H::FourOfAKind, H::FourOfAKind, H::ThreeOfAKind],
+            classify(&hands)
+        )
+    }
+
+    #[test]
+    fn test_parse_card_classify() {
+        use HandType as H;
+
+        let (input, _) = parse_input(INPUT);
+        let hands = input
+            .into_iter()
+            .map(|line| Hand::parse(line,CAMEL_ORDER_PART1,None))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            vec![H::OnePair, H::ThreeOfAKind, H::TwoPair, H::TwoPair, H::ThreeOfAKind, H::OnePair],
+            classify(&hands)
+        )
+    }
+
+    fn order(hands: &[Hand]) -> Vec<&str> {
+        hands.iter()
+            .enumerate()
+            .inspect(|(i,h)| print!("Rank {i} - {:?} => ",(&h.layout,&h.ord_layout,&h.hands_type)))
+            .map(|(_,h)| h.layout.as_str())
+            .inspect(|h| println!("{:?}", h))
+            .collect::<Vec<&str>>()
+    }
+    fn classify(hands: &[Hand]) -> Vec<HandType> {
+        hands.iter()
+            .inspect(|h| print!("{:?} => ",(&h.layout,&h.ord_layout)))
+            .map(|h| h.hands_type)
+            .inspect(|ht| println!("{:?}",ht))
+            .collect::<Vec<HandType>>()
+    }
+
+    fn parse_input(input: &str) -> (Vec<&str>,Vec<u32>) {
+        input.lines()
+            .map(|line|{
+                let mut split = line.split_ascii_whitespace();
+                (split.next().unwrap(),split.next().unwrap().parse::<u32>().expect("Ops!"))
+            })
+            .unzip()
+    }
+
+    static INPUT: &str= "32T3K 765\nT55J5 684\nKK677 28\nKTJJT 220\nQQQJA 483\nJJ958 123";
+}
+
@@ -6,12 +6,12 @@ use std::{
 };
 
 #[derive(Debug,PartialEq)]
-pub(crate) struct Network {
-    pub(crate) net: HashMap<Rc<str>,(Rc<str>, Rc<str>)>,
+pub struct Network {
+    pub net: HashMap<Rc<str>,(Rc<str>, Rc<str>)>,
 }
 
 impl Network {
-    pub(crate) fn iter(
+    pub fn iter(
         self: Rc<Self>,
         start: &str,
         turns: impl Iterator<Item=char>
@@ -72,7 +72,7 @@ impl FromStr for Network {
     }
 }
 
-pub(crate) struct NetworkIter<I> where I: Iterator<Item=char> {
+pub struct NetworkIter<I> where I: Iterator<Item=char> {
     net: Rc<Network>,
     key: Rc<str>,
     turns: I
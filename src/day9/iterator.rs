@@ -1,5 +1,5 @@
 use std::rc::Rc;
-use crate::sequence::*;
+use crate::day9::sequence::*;
 
 fn reduce_level(
     vec: &[Number],
@@ -12,12 +12,12 @@ fn reduce_level(
         .collect::<Rc<[Number]>>()
 }
 
-pub(crate) struct FwdIterator {
+pub struct FwdIterator {
     seq: Vec<Number>
 }
 
 impl FwdIterator {
-    pub(crate) fn new(vec: &[Number]) -> FwdIterator {
+    pub fn new(vec: &[Number]) -> FwdIterator {
         FwdIterator { seq: vec.to_vec() }
     }
     fn predict_next(history: &[Number]) -> Number {
@@ -40,7 +40,7 @@ impl Iterator for FwdIterator {
     }
 }
 
-pub(crate) struct BkwIterator {
+pub struct BkwIterator {
     seq: Vec<Number>
 }
 
@@ -0,0 +1,35 @@
+pub mod sequence;
+pub mod iterator;
+
+use std::rc::Rc;
+use sequence::{Sequence,Number};
+use crate::Solution;
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+    type Input = Rc<[Sequence]>;
+    type Part1 = Number;
+    type Part2 = Number;
+
+    fn parse(input: &str) -> Rc<[Sequence]> {
+        input
+            .lines()
+            .map(|line| line
+                .parse::<Sequence>()
+                .unwrap_or_else(|e| panic!("Ops! {} -> {:?}",e, line))
+            )
+            .collect::<Rc<_>>()
+    }
+    fn part1(seqs: &Rc<[Sequence]>) -> Number {
+        seqs.iter()
+            .map(|seq| seq.iter_forward().next().unwrap() )
+            .sum::<Number>()
+    }
+    fn part2(seqs: &Rc<[Sequence]>) -> Number {
+        seqs.iter()
+            .map(|seq| seq.iter_backward().next().unwrap() )
+            .sum::<Number>()
+    }
+}
@@ -1,18 +1,18 @@
-use crate::iterator::*;
+use crate::day9::iterator::*;
 use std::{num::ParseIntError, rc::Rc, str::FromStr};
 
-pub(crate) type Number = i32;
+pub type Number = i32;
 
 #[derive(Debug, PartialEq)]
-pub(crate) struct Sequence {
-    pub(crate) history: Rc<[Number]>
+pub struct Sequence {
+    pub history: Rc<[Number]>
 }
 
 impl Sequence {
-    pub(crate) fn iter_forward(&self) -> FwdIterator {
+    pub fn iter_forward(&self) -> FwdIterator {
         FwdIterator::new(&self.history)
     }
-    pub(crate) fn iter_backward(&self) -> BkwIterator {
+    pub fn iter_backward(&self) -> BkwIterator {
         BkwIterator::new(&self.history)
     }
 }
@@ -0,0 +1,362 @@
+use std::{ops::{Index, IndexMut}, str::FromStr};
+use thiserror::Error;
+use Direction as D;
+
+/// Column `x` and row `y` of a cell within a grid
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Coord {
+    pub x: usize,
+    pub y: usize
+}
+
+impl Coord {
+    pub fn new(x: usize, y: usize) -> Coord {
+        Coord { x, y }
+    }
+    /// Offsets the coordinate, returning `None` if it falls below zero
+    pub fn offset(&self, dx: isize, dy: isize) -> Option<Coord> {
+        Some(Coord {
+            x: self.x.checked_add_signed(dx)?,
+            y: self.y.checked_add_signed(dy)?
+        })
+    }
+}
+
+impl From<(usize, usize)> for Coord {
+    fn from((x, y): (usize, usize)) -> Self {
+        Coord { x, y }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction { Up=0, Right, Down, Left }
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [D::Up, D::Right, D::Down, D::Left];
+
+    pub fn reverse(&self) -> Direction {
+        match self { D::Up => D::Down, D::Right => D::Left, D::Down => D::Up, D::Left => D::Right }
+    }
+    pub fn turn_left(&self) -> Direction {
+        match self { D::Up => D::Left, D::Right => D::Up, D::Down => D::Right, D::Left => D::Down }
+    }
+    pub fn turn_right(&self) -> Direction {
+        match self { D::Up => D::Right, D::Right => D::Down, D::Down => D::Left, D::Left => D::Up }
+    }
+    /// The two directions at right angle, always in `Up, Down` or `Left, Right` order
+    pub fn perpendicular(&self) -> [Direction; 2] {
+        match self {
+            D::Up | D::Down => [D::Left, D::Right],
+            D::Left | D::Right => [D::Up, D::Down],
+        }
+    }
+    /// Unit step `(dx, dy)` with `y` growing downwards
+    pub fn offset(&self) -> (isize, isize) {
+        match self { D::Up => (0, -1), D::Right => (1, 0), D::Down => (0, 1), D::Left => (-1, 0) }
+    }
+}
+
+// 8-connected neighbour offsets, clockwise starting from the upper left cell
+const NEIGHBOURS8: [(isize, isize); 8] = [(-1,-1), (0,-1), (1,-1), (1,0), (1,1), (0,1), (-1,1), (-1,0)];
+
+#[derive(Debug, Error, PartialEq)]
+pub enum GridError {
+    #[error("Grid input is empty")]
+    Empty,
+    #[error("Line {line}: expected {expected} columns, found {found} in {text:?}")]
+    RaggedLine { line: usize, expected: usize, found: usize, text: String },
+    #[error("Line {line}, column {column}: unexpected character {found:?}")]
+    InvalidCell { line: usize, column: usize, found: char },
+    #[error("Cannot shape {len} cells into a {width}x{height} grid")]
+    Shape { len: usize, width: usize, height: usize },
+}
+
+impl GridError {
+    /// Shifts the reported line by `lines`, for grids parsed out of a larger input
+    pub fn offset_lines(self, lines: usize) -> GridError {
+        match self {
+            GridError::RaggedLine { line, expected, found, text } =>
+                GridError::RaggedLine { line: line + lines, expected, found, text },
+            GridError::InvalidCell { line, column, found } =>
+                GridError::InvalidCell { line: line + lines, column, found },
+            err => err
+        }
+    }
+}
+
+/// A rectangular grid of cells stored row by row in a flat buffer
+///
+/// Cells can be addressed either by `Coord` or by their flat index `y * width + x`
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Box<[T]>
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, cells: impl Into<Box<[T]>>) -> Result<Grid<T>, GridError> {
+        let cells = cells.into();
+        if cells.len() != width * height {
+            return Err(GridError::Shape { len: cells.len(), width, height })
+        }
+        Ok(Grid { width, height, cells })
+    }
+    pub fn filled(width: usize, height: usize, value: T) -> Grid<T> where T: Clone {
+        Grid { width, height, cells: vec![value; width * height].into() }
+    }
+    /// Parses one cell per character, one row per line; every line must have the same length
+    /// and `cell` returns `None` for characters that aren't valid cells
+    pub fn parse_with(s: &str, mut cell: impl FnMut(char) -> Option<T>) -> Result<Grid<T>, GridError> {
+        let width = s.lines().next().map(|line| line.chars().count()).unwrap_or(0);
+        if width == 0 { return Err(GridError::Empty) }
+        let mut cells = Vec::with_capacity(s.len());
+        let mut height = 0;
+
+        for (y, line) in s.lines().enumerate() {
+            let mut found = 0;
+            for (x, c) in line.chars().enumerate() {
+                cells.push(cell(c).ok_or(GridError::InvalidCell { line: y + 1, column: x + 1, found: c })?);
+                found += 1;
+            }
+            if found != width {
+                return Err(GridError::RaggedLine { line: y + 1, expected: width, found, text: line.to_string() })
+            }
+            height += 1;
+        }
+
+        Ok(Grid { width, height, cells: cells.into() })
+    }
+
+    #[inline]
+    pub fn width(&self) -> usize { self.width }
+    #[inline]
+    pub fn height(&self) -> usize { self.height }
+    #[inline]
+    pub fn len(&self) -> usize { self.cells.len() }
+    #[inline]
+    pub fn is_empty(&self) -> bool { self.cells.is_empty() }
+
+    #[inline]
+    pub fn index_of(&self, c: Coord) -> usize { c.y * self.width + c.x }
+    #[inline]
+    pub fn coord_of(&self, idx: usize) -> Coord { Coord { x: idx % self.width, y: idx / self.width } }
+    #[inline]
+    pub fn contains(&self, c: Coord) -> bool { c.x < self.width && c.y < self.height }
+
+    pub fn get(&self, c: Coord) -> Option<&T> {
+        if self.contains(c) { Some(&self.cells[self.index_of(c)]) } else { None }
+    }
+    pub fn get_mut(&mut self, c: Coord) -> Option<&mut T> {
+        if self.contains(c) { let idx = self.index_of(c); Some(&mut self.cells[idx]) } else { None }
+    }
+    pub fn cells(&self) -> &[T] { &self.cells }
+    pub fn cells_mut(&mut self) -> &mut [T] { &mut self.cells }
+    pub fn iter(&self) -> std::slice::Iter<'_, T> { self.cells.iter() }
+
+    /// Moves one cell from flat index `idx` towards `dir`, returning `None` at the grid's edges
+    pub fn step(&self, idx: usize, dir: Direction) -> Option<usize> {
+        if idx >= self.cells.len() { return None }
+        match dir {
+            D::Right if !(idx + 1).is_multiple_of(self.width) => Some(idx + 1),
+            D::Left if !idx.is_multiple_of(self.width) => Some(idx - 1),
+            D::Up if idx >= self.width => Some(idx - self.width),
+            D::Down if idx + self.width < self.cells.len() => Some(idx + self.width),
+            _ => None
+        }
+    }
+    /// Moves one cell from `c` towards `dir`, returning `None` at the grid's edges
+    pub fn move_from(&self, c: Coord, dir: Direction) -> Option<Coord> {
+        let (dx, dy) = dir.offset();
+        c.offset(dx, dy).filter(|&n| self.contains(n))
+    }
+    /// Up, right, down and left neighbours that lie within the grid
+    pub fn neighbours4(&self, c: Coord) -> impl Iterator<Item=Coord> + '_ {
+        Direction::ALL.into_iter().filter_map(move |d| self.move_from(c, d))
+    }
+    /// All eight surrounding neighbours, including diagonals, that lie within the grid
+    pub fn neighbours8(&self, c: Coord) -> impl Iterator<Item=Coord> + '_ {
+        NEIGHBOURS8.into_iter()
+            .filter_map(move |(dx, dy)| c.offset(dx, dy))
+            .filter(|&n| self.contains(n))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+    /// Every row top to bottom, empty ones included when the grid has no columns
+    pub fn rows(&self) -> impl Iterator<Item=&[T]> {
+        (0..self.height).map(|y| self.row(y))
+    }
+    pub fn column(&self, x: usize) -> impl Iterator<Item=&T> {
+        // a grid without columns has no cells to step over
+        self.cells.iter().skip(x).step_by(self.width.max(1))
+    }
+    pub fn columns(&self) -> impl Iterator<Item=impl Iterator<Item=&T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid { width: self.width, height: self.height, cells: self.cells.iter().map(f).collect() }
+    }
+    /// Builds a `width` x `height` grid taking each cell from `self` at the coordinate `src` returns
+    fn remap(&self, width: usize, height: usize, src: impl Fn(usize, usize) -> Coord) -> Grid<T> where T: Clone {
+        Grid {
+            width, height,
+            cells: (0..height)
+                .flat_map(|y| (0..width).map(move |x| (x, y)))
+                .map(|(x, y)| self[src(x, y)].clone())
+                .collect()
+        }
+    }
+    /// Mirrors the grid along its main diagonal, so rows become columns
+    pub fn transpose(&self) -> Grid<T> where T: Clone {
+        self.remap(self.height, self.width, |x, y| Coord::new(y, x))
+    }
+    /// Rotates the grid 90 degrees clockwise
+    pub fn rotate_cw(&self) -> Grid<T> where T: Clone {
+        self.remap(self.height, self.width, |x, y| Coord::new(y, self.height - 1 - x))
+    }
+    /// Rotates the grid 90 degrees counter-clockwise
+    pub fn rotate_ccw(&self) -> Grid<T> where T: Clone {
+        self.remap(self.height, self.width, |x, y| Coord::new(self.width - 1 - y, x))
+    }
+}
+
+impl<T> Index<Coord> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, c: Coord) -> &Self::Output {
+        &self.cells[self.index_of(c)]
+    }
+}
+
+impl<T> IndexMut<Coord> for Grid<T> {
+    fn index_mut(&mut self, c: Coord) -> &mut Self::Output {
+        let idx = self.index_of(c);
+        &mut self.cells[idx]
+    }
+}
+
+impl<T> Index<usize> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, idx: usize) -> &Self::Output {
+        &self.cells[idx]
+    }
+}
+
+impl<T> IndexMut<usize> for Grid<T> {
+    fn index_mut(&mut self, idx: usize) -> &mut Self::Output {
+        &mut self.cells[idx]
+    }
+}
+
+impl FromStr for Grid<char> {
+    type Err = GridError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Grid::parse_with(s, Some)
+    }
+}
+
+impl FromStr for Grid<u8> {
+    type Err = GridError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Grid::parse_with(s, |c| c.is_ascii().then_some(c as u8))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    static INPUT: &str = "abc\ndef";
+
+    #[test]
+    fn test_parse_grid() {
+        let grid = INPUT.parse::<Grid<char>>().expect("Ops!");
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Coord::new(2, 1)], 'f');
+        assert_eq!(grid[4], 'e');
+        assert_eq!(grid.get(Coord::new(3, 0)), None);
+
+        assert_eq!("ab\nc".parse::<Grid<char>>(), Err(GridError::RaggedLine { line: 2, expected: 2, found: 1, text: "c".into() }));
+        assert_eq!("".parse::<Grid<char>>(), Err(GridError::Empty));
+        assert_eq!(
+            Grid::parse_with("12\n3x", |c| c.to_digit(10)),
+            Err(GridError::InvalidCell { line: 2, column: 2, found: 'x' })
+        );
+    }
+
+    #[test]
+    fn test_step_within_bounds() {
+        let grid = INPUT.parse::<Grid<char>>().expect("Ops!");
+        let data = [
+            ((0, D::Left), None), ((0, D::Up), None), ((0, D::Right), Some(1)), ((0, D::Down), Some(3)),
+            ((2, D::Right), None), ((3, D::Left), None), ((5, D::Down), None), ((5, D::Up), Some(2)),
+        ];
+        for ((idx, dir), out) in data {
+            assert_eq!(grid.step(idx, dir), out);
+            assert_eq!(grid.move_from(grid.coord_of(idx), dir).map(|c| grid.index_of(c)), out);
+        }
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = INPUT.parse::<Grid<char>>().expect("Ops!");
+        let at = |c: Coord| grid[c];
+
+        assert_eq!(grid.neighbours4(Coord::new(0, 0)).map(at).collect::<String>(), "bd");
+        assert_eq!(grid.neighbours4(Coord::new(1, 1)).map(at).collect::<String>(), "bfd");
+        assert_eq!(grid.neighbours8(Coord::new(1, 0)).map(at).collect::<String>(), "cfeda");
+        assert_eq!(grid.neighbours8(Coord::new(2, 1)).map(at).collect::<String>(), "bce");
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = INPUT.parse::<Grid<char>>().expect("Ops!");
+
+        assert_eq!(grid.row(1), ['d', 'e', 'f']);
+        assert_eq!(grid.rows().count(), 2);
+        assert_eq!(grid.column(1).collect::<String>(), "be");
+        assert_eq!(
+            grid.columns().map(|c| c.collect::<String>()).collect::<Vec<_>>(),
+            ["ad", "be", "cf"]
+        );
+    }
+
+    #[test]
+    fn test_empty_grid() {
+        let grid = Grid::<u8>::new(0, 3, []).expect("Ops!");
+        assert!(grid.is_empty());
+        assert_eq!(grid.rows().collect::<Vec<_>>(), [[]; 3]);
+        assert_eq!(grid.column(0).count(), 0);
+        assert_eq!(grid.columns().count(), 0);
+        assert_eq!(grid.transpose().rows().count(), 0);
+
+        let grid = Grid::<u8>::default();
+        assert_eq!((grid.rows().count(), grid.column(0).count()), (0, 0));
+        assert_eq!(Grid::filled(0, 2, 'x').rows().count(), 2);
+    }
+
+    #[test]
+    fn test_transpose_and_rotate() {
+        let grid = INPUT.parse::<Grid<char>>().expect("Ops!");
+
+        assert_eq!(grid.transpose(), "ad\nbe\ncf".parse::<Grid<char>>().unwrap());
+        assert_eq!(grid.rotate_cw(), "da\neb\nfc".parse::<Grid<char>>().unwrap());
+        assert_eq!(grid.rotate_ccw(), "cf\nbe\nad".parse::<Grid<char>>().unwrap());
+        assert_eq!(grid.rotate_cw().rotate_ccw(), grid);
+        assert_eq!(grid.rotate_cw().rotate_cw().rotate_cw().rotate_cw(), grid);
+    }
+
+    #[test]
+    fn test_direction_turns() {
+        assert!(Direction::ALL.iter().all(|d| d.turn_left().turn_right() == *d));
+        assert!(Direction::ALL.iter().all(|d| d.reverse().reverse() == *d));
+        assert_eq!(D::Right.perpendicular(), [D::Up, D::Down]);
+        assert_eq!(D::Down.perpendicular(), [D::Left, D::Right]);
+    }
+}
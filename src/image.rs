@@ -0,0 +1,235 @@
+use std::{fmt::{Display, Formatter, Write as _}, io, path::Path};
+
+/// A 24-bit colour
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const WHITE: Rgb = Rgb(255, 255, 255);
+    pub const BLACK: Rgb = Rgb(0, 0, 0);
+
+    /// Blends towards `other`, from `self` at `t = 0` to `other` at `t = 1`
+    pub fn lerp(self, other: Rgb, t: f64) -> Rgb {
+        let t = t.clamp(0.0, 1.0);
+        let mix = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * t).round() as u8;
+        Rgb(mix(self.0, other.0), mix(self.1, other.1), mix(self.2, other.2))
+    }
+    /// Colour at `t` within `0..=1` along evenly spaced colour stops
+    pub fn gradient(stops: &[Rgb], t: f64) -> Rgb {
+        match stops {
+            [] => Rgb::BLACK,
+            [only] => *only,
+            _ => {
+                let t = t.clamp(0.0, 1.0) * (stops.len() - 1) as f64;
+                let i = (t.floor() as usize).min(stops.len() - 2);
+                stops[i].lerp(stops[i + 1], t - i as f64)
+            }
+        }
+    }
+}
+
+impl Display for Rgb {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "#{:02x}{:02x}{:02x}", self.0, self.1, self.2)
+    }
+}
+
+/// Yellow through orange to dark red, for values going from low to high
+pub const HEAT_STOPS: [Rgb; 5] = [
+    Rgb(255, 255, 178), Rgb(254, 204, 92), Rgb(253, 141, 60), Rgb(240, 59, 32), Rgb(189, 0, 38)
+];
+
+/// An image as rows of pixels, top to bottom
+#[derive(Debug, Clone, PartialEq)]
+pub struct Raster {
+    width: usize,
+    height: usize,
+    pixels: Vec<Rgb>,
+}
+
+impl Raster {
+    pub fn new(width: usize, height: usize, background: Rgb) -> Raster {
+        Raster { width, height, pixels: vec![background; width * height] }
+    }
+    pub fn width(&self) -> usize { self.width }
+    pub fn height(&self) -> usize { self.height }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<Rgb> {
+        (x < self.width && y < self.height).then(|| self.pixels[y * self.width + x])
+    }
+    pub fn set(&mut self, x: usize, y: usize, rgb: Rgb) {
+        if x < self.width && y < self.height { self.pixels[y * self.width + x] = rgb }
+    }
+    /// Paints the `w` by `h` pixels with their top left corner at (`x`,`y`), clipped to the image
+    pub fn fill_rect(&mut self, x: usize, y: usize, w: usize, h: usize, rgb: Rgb) {
+        for py in y..(y + h).min(self.height) {
+            self.pixels[py * self.width + x.min(self.width) .. py * self.width + (x + w).min(self.width)].fill(rgb);
+        }
+    }
+    /// Binary portable pixmap (`P6`) encoding
+    pub fn to_ppm(&self) -> Vec<u8> {
+        let mut out = format!("P6\n{} {}\n255\n", self.width, self.height).into_bytes();
+        out.extend(self.pixels.iter().flat_map(|p| [p.0, p.1, p.2]));
+        out
+    }
+    /// PNG encoding, left uncompressed as zlib allows, so that no compression library is needed
+    pub fn to_png(&self) -> Vec<u8> {
+        // every row starts with filter type 0, i.e. no filtering
+        let raw = self.pixels.chunks(self.width.max(1))
+            .flat_map(|row| std::iter::once(0).chain(row.iter().flat_map(|p| [p.0, p.1, p.2])))
+            .collect::<Vec<u8>>();
+
+        let mut zlib = vec![0x78, 0x01];
+        let mut blocks = raw.chunks(u16::MAX as usize).peekable();
+        if blocks.peek().is_none() { zlib.extend([1, 0, 0, 0xff, 0xff]) }
+        while let Some(block) = blocks.next() {
+            let len = block.len() as u16;
+            zlib.push(blocks.peek().is_none() as u8);
+            zlib.extend(len.to_le_bytes());
+            zlib.extend((!len).to_le_bytes());
+            zlib.extend(block);
+        }
+        zlib.extend(adler32(&raw).to_be_bytes());
+
+        let mut header = (self.width as u32).to_be_bytes().to_vec();
+        header.extend((self.height as u32).to_be_bytes());
+        // 8 bits per channel, truecolour, default compression & filtering, no interlacing
+        header.extend([8, 2, 0, 0, 0]);
+
+        let mut out = b"\x89PNG\r\n\x1a\n".to_vec();
+        for (kind, data) in [(b"IHDR", header), (b"IDAT", zlib), (b"IEND", vec![])] {
+            out.extend((data.len() as u32).to_be_bytes());
+            let start = out.len();
+            out.extend(kind);
+            out.extend(&data);
+            let crc = crc32(&out[start..]);
+            out.extend(crc.to_be_bytes());
+        }
+        out
+    }
+    /// Writes the image as PNG, or as PPM when the file name ends in `.ppm`
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let path = path.as_ref();
+        match path.extension().and_then(|e| e.to_str()) {
+            Some("ppm") => std::fs::write(path, self.to_ppm()),
+            _ => std::fs::write(path, self.to_png()),
+        }
+    }
+}
+
+fn adler32(data: &[u8]) -> u32 {
+    let (a, b) = data.iter().fold((1u32, 0u32), |(a, b), &d| {
+        let a = (a + d as u32) % 65521;
+        (a, (b + a) % 65521)
+    });
+    (b << 16) | a
+}
+
+fn crc32(data: &[u8]) -> u32 {
+    !data.iter().fold(!0u32, |crc, &d| {
+        (0..8).fold(crc ^ d as u32, |c, _| if c & 1 == 1 { (c >> 1) ^ 0xedb8_8320 } else { c >> 1 })
+    })
+}
+
+/// An SVG document built up out of shapes, drawn in the order added
+#[derive(Debug, Clone)]
+pub struct Svg {
+    width: f64,
+    height: f64,
+    body: String,
+}
+
+impl Svg {
+    pub fn new(width: f64, height: f64) -> Svg {
+        Svg { width, height, body: String::new() }
+    }
+    pub fn rect(&mut self, x: f64, y: f64, w: f64, h: f64, fill: Rgb) -> &mut Self {
+        let _ = writeln!(self.body, r#"<rect x="{x}" y="{y}" width="{w}" height="{h}" fill="{fill}"/>"#);
+        self
+    }
+    pub fn line(&mut self, from: (f64, f64), to: (f64, f64), stroke: Rgb, width: f64) -> &mut Self {
+        let _ = writeln!(
+            self.body,
+            r#"<line x1="{}" y1="{}" x2="{}" y2="{}" stroke="{stroke}" stroke-width="{width}" stroke-linecap="round"/>"#,
+            from.0, from.1, to.0, to.1
+        );
+        self
+    }
+    pub fn polygon(&mut self, points: impl IntoIterator<Item=(f64, f64)>, fill: Rgb) -> &mut Self {
+        let points = points.into_iter().map(|(x, y)| format!("{x},{y}")).collect::<Vec<_>>().join(" ");
+        let _ = writeln!(self.body, r#"<polygon points="{points}" fill="{fill}"/>"#);
+        self
+    }
+    pub fn circle(&mut self, centre: (f64, f64), r: f64, fill: Rgb) -> &mut Self {
+        let _ = writeln!(self.body, r#"<circle cx="{}" cy="{}" r="{r}" fill="{fill}"/>"#, centre.0, centre.1);
+        self
+    }
+    /// Text centred on `at`, with `&`, `<` and `>` escaped
+    pub fn text(&mut self, at: (f64, f64), size: f64, fill: Rgb, text: &str) -> &mut Self {
+        let text = text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;");
+        let _ = writeln!(
+            self.body,
+            r#"<text x="{}" y="{}" font-size="{size}" fill="{fill}" text-anchor="middle" dominant-baseline="central">{text}</text>"#,
+            at.0, at.1
+        );
+        self
+    }
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        std::fs::write(path, self.to_string())
+    }
+}
+
+impl Display for Svg {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w}" height="{h}" viewBox="0 0 {w} {h}">"#,
+            w = self.width, h = self.height
+        )?;
+        write!(f, "{}</svg>", self.body)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_gradient() {
+        assert_eq!(Rgb::BLACK.lerp(Rgb::WHITE, 0.5), Rgb(128, 128, 128));
+        assert_eq!(Rgb::gradient(&HEAT_STOPS, 0.0), HEAT_STOPS[0]);
+        assert_eq!(Rgb::gradient(&HEAT_STOPS, 0.5), HEAT_STOPS[2]);
+        assert_eq!(Rgb::gradient(&HEAT_STOPS, 1.5), HEAT_STOPS[4]);
+        assert_eq!(format!("{}", Rgb(255, 8, 171)), "#ff08ab");
+    }
+
+    #[test]
+    fn test_raster() {
+        let mut img = Raster::new(4, 3, Rgb::WHITE);
+        img.fill_rect(2, 1, 5, 5, Rgb(1, 2, 3));
+        assert_eq!((img.get(1, 1), img.get(3, 2), img.get(4, 2)), (Some(Rgb::WHITE), Some(Rgb(1, 2, 3)), None));
+
+        let ppm = img.to_ppm();
+        assert!(ppm.starts_with(b"P6\n4 3\n255\n"));
+        assert_eq!(ppm.len(), 11 + 4 * 3 * 3);
+
+        let png = img.to_png();
+        assert!(png.starts_with(b"\x89PNG\r\n\x1a\n\0\0\0\x0dIHDR\0\0\0\x04\0\0\0\x03"));
+        assert!(png.ends_with(b"IEND\xae\x42\x60\x82"));
+        assert_eq!(adler32(b"Wikipedia"), 0x11e6_0398);
+    }
+
+    #[test]
+    fn test_svg() {
+        let mut svg = Svg::new(10.0, 20.0);
+        svg.rect(0.0, 0.0, 10.0, 10.0, Rgb::BLACK)
+            .polygon([(0.0, 0.0), (10.0, 0.0), (5.0, 2.5)], Rgb(255, 0, 0))
+            .text((5.0, 15.0), 4.0, Rgb::WHITE, "a<b");
+        let out = svg.to_string();
+        assert!(out.starts_with(r#"<svg xmlns="http://www.w3.org/2000/svg" width="10" height="20""#));
+        assert!(out.contains(r##"<rect x="0" y="0" width="10" height="10" fill="#000000"/>"##));
+        assert!(out.contains(r##"<polygon points="0,0 10,0 5,2.5" fill="#ff0000"/>"##));
+        assert!(out.contains(">a&lt;b</text>"));
+        assert!(out.ends_with("</svg>"));
+    }
+}
@@ -0,0 +1,87 @@
+use std::{io::Read, path::PathBuf};
+use thiserror::Error;
+
+/// Environment variable pointing to a directory laid out as `<dir>/day<N>/input.txt`
+pub const INPUTS_DIR_VAR: &str = "AOC_INPUTS";
+
+#[derive(Debug, Error)]
+pub enum InputError {
+    #[error("Input file [{0}] not found")]
+    NotFound(PathBuf),
+    #[error("Cannot read input file [{0}]: {1}")]
+    Unreadable(PathBuf, std::io::Error),
+    #[error("Cannot read input from stdin: {0}")]
+    Stdin(std::io::Error),
+}
+
+/// Where a day's puzzle input is read from
+#[derive(Debug, Clone, PartialEq)]
+pub enum InputSource {
+    Stdin,
+    File(PathBuf),
+}
+
+impl InputSource {
+    /// Resolves the input for `day` in order of precedence:
+    /// 1. `arg`, either a file path or `-` for stdin
+    /// 2. `$AOC_INPUTS/day<N>/input.txt`
+    /// 3. `src/bin/day<N>/input.txt` of this crate
+    pub fn resolve(day: u8, arg: Option<&str>) -> InputSource {
+        match arg {
+            Some("-") => InputSource::Stdin,
+            Some(path) => InputSource::File(path.into()),
+            None => InputSource::day_file(day, "input.txt"),
+        }
+    }
+
+    /// `$AOC_INPUTS/day<N>/<file>` when the variable is set, otherwise `src/bin/day<N>/<file>` of this crate
+    pub fn day_file(day: u8, file: &str) -> InputSource {
+        let dir = std::env::var_os(INPUTS_DIR_VAR)
+            .map(PathBuf::from)
+            .unwrap_or_else(|| [env!("CARGO_MANIFEST_DIR"), "src", "bin"].iter().collect());
+        InputSource::File(dir.join(format!("day{day}")).join(file))
+    }
+
+    pub fn read(&self) -> Result<String, InputError> {
+        match self {
+            InputSource::Stdin => {
+                let mut buf = String::new();
+                std::io::stdin().read_to_string(&mut buf).map_err(InputError::Stdin)?;
+                Ok(buf)
+            }
+            InputSource::File(path) => std::fs::read_to_string(path)
+                .map_err(|e| match e.kind() {
+                    std::io::ErrorKind::NotFound => InputError::NotFound(path.clone()),
+                    _ => InputError::Unreadable(path.clone(), e),
+                }),
+        }
+    }
+}
+
+/// Reads the puzzle input for `day`; see [`InputSource::resolve`] for how `arg` is interpreted
+pub fn read_input(day: u8, arg: Option<&str>) -> Result<String, InputError> {
+    InputSource::resolve(day, arg).read()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_resolve_input_source() {
+        assert_eq!(InputSource::resolve(3, Some("-")), InputSource::Stdin);
+        assert_eq!(InputSource::resolve(3, Some("my.txt")), InputSource::File("my.txt".into()));
+        if std::env::var_os(INPUTS_DIR_VAR).is_none() {
+            assert_eq!(
+                InputSource::resolve(3, None),
+                InputSource::File([env!("CARGO_MANIFEST_DIR"), "src", "bin", "day3", "input.txt"].iter().collect())
+            );
+        }
+    }
+
+    #[test]
+    fn test_missing_input_names_file() {
+        let err = read_input(3, Some("no/such/input.txt")).expect_err("file should not exist");
+        assert_eq!(err.to_string(), "Input file [no/such/input.txt] not found");
+    }
+}
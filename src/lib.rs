@@ -1,14 +1,123 @@
-pub fn add(left: usize, right: usize) -> usize {
-    left + right
+mod solution;
+mod input;
+pub mod grid;
+pub mod image;
+pub mod bench;
+pub mod answers;
+
+pub mod day1;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+
+pub use solution::{Solution, Puzzle, Solve, catch_panic};
+pub use input::{read_input, InputSource, InputError, INPUTS_DIR_VAR};
+
+/// Registry of every implemented day, in calendar order
+pub static SOLUTIONS: [&dyn Puzzle; 25] = [
+    &day1::Day1,
+    &day2::Day2,
+    &day3::Day3,
+    &day4::Day4,
+    &day5::Day5,
+    &day6::Day6,
+    &day7::Day7,
+    &day8::Day8,
+    &day9::Day9,
+    &day10::Day10,
+    &day11::Day11,
+    &day12::Day12,
+    &day13::Day13,
+    &day14::Day14,
+    &day15::Day15,
+    &day16::Day16,
+    &day17::Day17,
+    &day18::Day18,
+    &day19::Day19,
+    &day20::Day20,
+    &day21::Day21,
+    &day22::Day22,
+    &day23::Day23,
+    &day24::Day24,
+    &day25::Day25,
+];
+
+/// Look up the puzzle registered for the given day
+pub fn solution(day: u8) -> Option<&'static dyn Puzzle> {
+    SOLUTIONS.iter().find(|p| p.day() == day).copied()
+}
+
+/// Days of all registered puzzles, in calendar order
+pub fn all_days() -> Vec<u8> {
+    SOLUTIONS.iter().map(|p| p.day()).collect()
+}
+
+/// Parses a selection of registered days given as `all`, a single day `5`, a range `3..7` / `3-7` or a list `1,5,9`
+pub fn parse_days(arg: &str) -> Result<Vec<u8>, String> {
+    let day = |s: &str| -> Result<u8, String> {
+        match s.trim().parse::<u8>() {
+            Ok(d) if solution(d).is_some() => Ok(d),
+            _ => Err(format!("invalid day {s:?}; expected one of {:?}", all_days())),
+        }
+    };
+
+    if arg == "all" {
+        return Ok(all_days())
+    }
+    if let Some((from, to)) = arg.split_once("..").or_else(|| arg.split_once('-')) {
+        return Ok((day(from)?..=day(to.trim_start_matches('='))?).collect())
+    }
+    arg.split(',').map(day).collect()
 }
 
 #[cfg(test)]
-mod tests {
+mod test {
     use super::*;
 
     #[test]
-    fn it_works() {
-        let result = add(2, 2);
-        assert_eq!(result, 4);
+    fn test_registry_in_calendar_order() {
+        assert!(SOLUTIONS.windows(2).all(|w| w[0].day() < w[1].day()));
+        assert_eq!(solution(5).map(|p| p.day()), Some(5));
+        assert!(solution(0).is_none());
+    }
+
+    #[test]
+    fn test_parse_days() {
+        assert_eq!(parse_days("all"), Ok(all_days()));
+        assert_eq!(parse_days("5"), Ok(vec![5]));
+        assert_eq!(parse_days("3..5"), Ok(vec![3, 4, 5]));
+        assert_eq!(parse_days("3-5"), Ok(vec![3, 4, 5]));
+        assert_eq!(parse_days("1, 5,9"), Ok(vec![1, 5, 9]));
+        assert!(parse_days("0").is_err());
+        assert!(parse_days("x").is_err());
+    }
+
+    #[test]
+    fn test_solve_through_registry() {
+        let input = std::fs::read_to_string("src/bin/day5/sample.txt").expect("Ops!");
+        let parsed = solution(5).expect("day 5 not registered").parse(&input);
+        assert_eq!(parsed.part1(), "35");
+        assert_eq!(parsed.part2(), "46");
     }
 }
@@ -0,0 +1,58 @@
+use std::{fmt::Display, panic::{self, AssertUnwindSafe}};
+
+/// A day's puzzle; the input is parsed once and then shared by both parts
+pub trait Solution {
+    /// Advent calendar day the puzzle belongs to
+    const DAY: u8;
+    /// Parsed form of the puzzle input
+    type Input;
+    type Part1: Display;
+    type Part2: Display;
+
+    fn parse(input: &str) -> Self::Input;
+    fn part1(input: &Self::Input) -> Self::Part1;
+    fn part2(input: &Self::Input) -> Self::Part2;
+}
+
+/// Type erased `Solution` so that all days can be held in a single registry
+pub trait Puzzle: Sync {
+    fn day(&self) -> u8;
+    fn parse(&self, input: &str) -> Box<dyn Solve>;
+}
+
+/// Parsed puzzle input, ready to be solved for either part
+pub trait Solve {
+    fn part1(&self) -> String;
+    fn part2(&self) -> String;
+}
+
+struct Parsed<S: Solution>(S::Input);
+
+impl<S: Solution> Solve for Parsed<S> {
+    fn part1(&self) -> String {
+        S::part1(&self.0).to_string()
+    }
+    fn part2(&self) -> String {
+        S::part2(&self.0).to_string()
+    }
+}
+
+impl<S> Puzzle for S where S: Solution + Sync + 'static, S::Input: 'static {
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+    fn parse(&self, input: &str) -> Box<dyn Solve> {
+        Box::new(Parsed::<S>(S::parse(input)))
+    }
+}
+
+/// Runs `f`, turning a panic, such as a solver's on input it can't solve, into its message
+pub fn catch_panic<T>(f: impl FnOnce() -> T) -> Result<T, String> {
+    panic::catch_unwind(AssertUnwindSafe(f)).map_err(|payload| {
+        match (payload.downcast_ref::<&str>(), payload.downcast_ref::<String>()) {
+            (Some(msg), _) => format!("panicked: {msg}"),
+            (_, Some(msg)) => format!("panicked: {msg}"),
+            _ => "panicked".into(),
+        }
+    })
+}
@@ -0,0 +1,50 @@
+use advent2023::{answers::{Answers, Expected}, InputSource, Puzzle, SOLUTIONS};
+
+/// Solves every file of every registered day selected by `filter`, returning a line per wrong answer
+fn check_answers(filter: impl Fn(&Expected) -> bool) -> Vec<String> {
+    let mut failures = vec![];
+
+    for puzzle in SOLUTIONS.iter() {
+        let day = puzzle.day();
+        let answers = match Answers::load(day) {
+            Ok(answers) => answers,
+            Err(e) => { failures.push(format!("day {day}: {e}")); continue }
+        };
+        for expected in answers.iter().filter(|e| filter(e)) {
+            failures.extend(check(*puzzle, expected).err());
+        }
+    }
+    failures
+}
+
+fn check(puzzle: &dyn Puzzle, expected: &Expected) -> Result<(), String> {
+    let day = puzzle.day();
+    let input = InputSource::day_file(day, &expected.file)
+        .read()
+        .map_err(|e| format!("day {day}: {e}"))?;
+    let parsed = puzzle.parse(&input);
+
+    let mut wrong = vec![];
+    if let Some(part1) = &expected.part1 {
+        let found = parsed.part1();
+        if &found != part1 { wrong.push(format!("part 1 expected {part1}, found {found}")) }
+    }
+    if let Some(part2) = &expected.part2 {
+        let found = parsed.part2();
+        if &found != part2 { wrong.push(format!("part 2 expected {part2}, found {found}")) }
+    }
+    if wrong.is_empty() { Ok(()) } else { Err(format!("day {day} {}: {}", expected.file, wrong.join(", "))) }
+}
+
+#[test]
+fn sample_answers() {
+    let failures = check_answers(|e| !e.is_input());
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}
+
+#[test]
+#[ignore = "solves every real puzzle input; run with `cargo test --release -- --ignored`"]
+fn input_answers() {
+    let failures = check_answers(Expected::is_input);
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}